@@ -0,0 +1,90 @@
+use std::fmt::Display;
+use std::io;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use serde_json::{to_string, to_string_pretty, Value};
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const BLUE: &str = "\x1b[34m";
+const CYAN: &str = "\x1b[36m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+static COLOR_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Disable colored output (e.g. for `--no-color`). Color is also disabled when the `NO_COLOR`
+/// environment variable is set, or when the stream being written to isn't a terminal.
+pub fn disable_color() {
+    COLOR_ENABLED.store(false, Ordering::Relaxed);
+}
+
+fn color_enabled(is_terminal: bool) -> bool {
+    let no_color: bool = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+
+    COLOR_ENABLED.load(Ordering::Relaxed) && !no_color && is_terminal
+}
+
+/// Print an error message to stderr, prefixed with a (red, if enabled) "Error:".
+pub fn print_error<T: Display>(message: T) {
+    if color_enabled(io::stderr().is_terminal()) {
+        eprintln!("{}Error:{} {}{}{}", BOLD_RED, RESET, RED, message, RESET);
+    } else {
+        eprintln!("Error: {}", message);
+    }
+}
+
+/// Print a JSON value to stdout, pretty-printed. Keys and values are colored when writing to a
+/// terminal, so piped (machine-read) output is always plain JSON.
+pub fn print_json(value: &Value) {
+    if color_enabled(io::stdout().is_terminal()) {
+        let mut output: String = String::new();
+        write_colored(value, 0, &mut output);
+
+        println!("{}", output);
+    } else {
+        println!("{}", to_string_pretty(value).unwrap());
+    }
+}
+
+/// Write a value in the same layout as `to_string_pretty`, with ANSI colors.
+fn write_colored(value: &Value, indent: usize, output: &mut String) {
+    let padding: String = "  ".repeat(indent + 1);
+    let closing_padding: String = "  ".repeat(indent);
+
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            output.push_str("{\n");
+
+            for (i, (key, value)) in map.iter().enumerate() {
+                let key: String = to_string(key).unwrap();
+                output.push_str(&format!("{}{}{}{}: ", padding, BLUE, key, RESET));
+                write_colored(value, indent + 1, output);
+
+                output.push_str(if i + 1 < map.len() { ",\n" } else { "\n" });
+            }
+
+            output.push_str(&closing_padding);
+            output.push('}');
+        },
+        Value::Array(values) if !values.is_empty() => {
+            output.push_str("[\n");
+
+            for (i, value) in values.iter().enumerate() {
+                output.push_str(&padding);
+                write_colored(value, indent + 1, output);
+
+                output.push_str(if i + 1 < values.len() { ",\n" } else { "\n" });
+            }
+
+            output.push_str(&closing_padding);
+            output.push(']');
+        },
+        Value::String(_) => output.push_str(&format!("{}{}{}", GREEN, value, RESET)),
+        Value::Number(_) => output.push_str(&format!("{}{}{}", YELLOW, value, RESET)),
+        Value::Bool(_) | Value::Null => output.push_str(&format!("{}{}{}", CYAN, value, RESET)),
+        // Empty objects and arrays.
+        _ => output.push_str(&value.to_string())
+    }
+}
\ No newline at end of file
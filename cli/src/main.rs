@@ -1,76 +1,189 @@
+use std::{env, io};
+use std::fs::{read_to_string, write};
+use std::io::BufRead;
+use std::process::exit;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use clap::{value_parser, Arg, ArgMatches, Command};
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use clap_complete::{generate, Shell};
 use serde::{Deserialize, Serialize};
-use serde_json::{json, to_string_pretty, to_value, Value};
+use serde_json::{from_str, from_value, json, to_string_pretty, to_value, Value};
 use serde_json::Value::Bool;
-use core::ApiService;
+use frogworks_core::api_error::{APIError, FieldError, StatusCode};
+use frogworks_core::application_version::NewApplicationVersion;
+use frogworks_core::catalog::ApplicationDefinition;
+use frogworks_core::daemon_client::{DaemonClient, DaemonMessage, DaemonResponse};
+use frogworks_core::deep_link::DeepLink;
+use frogworks_core::launch_context::{parse_launch_context, LaunchContext};
+use frogworks_core::download::CollisionPolicy;
+use frogworks_core::list_field::decode_list;
+use frogworks_core::{ApiResult, ApiService};
+use crate::color::{disable_color, print_error, print_json};
+
+mod color;
 
 const USER_AGENT_STRING: &str = "Frogworks CLI";
 const APPLICATION_VERSION: &str = "0.1.0-dev";
+const PASSWORD_ENV_VAR: &str = "FROGWORKS_PASSWORD";
+
+// Exit codes, so scripts can tell classes of errors apart (see `exit_code`).
+const EXIT_ERROR: i32 = 1;
+const EXIT_BAD_REQUEST: i32 = 2;
+const EXIT_UNAUTHORIZED: i32 = 3;
+const EXIT_NOT_FOUND: i32 = 4;
+const EXIT_NETWORK: i32 = 5;
+const EXIT_UNAVAILABLE: i32 = 6;
+const EXIT_CODES_HELP: &str = "Exit codes:
+  0  Success
+  1  Other error
+  2  Bad request or invalid usage
+  3  Unauthorized or forbidden
+  4  Not found
+  5  Network error (the server couldn't be reached)
+  6  Server unavailable (maintenance, rate limiting, or a server error)";
 
 trait CommandHandler {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value;
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value>;
 }
 
 struct Ping {}
 
 impl CommandHandler for Ping {
-    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> Value {
-        api_service.ping().unwrap()
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(to_value(api_service.ping()?)?)
+    }
+}
+
+struct GetServerLimits {}
+
+impl CommandHandler for GetServerLimits {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(to_value(api_service.get_server_limits()?)?)
+    }
+}
+
+struct Diagnose {}
+
+impl CommandHandler for Diagnose {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        let diagnosis = api_service.diagnose();
+
+        Ok(json!({
+            "success": diagnosis.success(),
+            "failed_stage": diagnosis.failed_stage(),
+            "stages": diagnosis.stages
+        }))
+    }
+}
+
+struct GetApiVersion {}
+
+impl CommandHandler for GetApiVersion {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(json!({
+            "version": api_service.get_api_version()?
+        }))
+    }
+}
+
+struct DaemonStatus {}
+
+impl CommandHandler for DaemonStatus {
+    fn handle_command(_api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        let message: DaemonMessage = DaemonMessage::new("ping", Value::Null);
+
+        Ok(json!({
+            "running": DaemonClient::new().send(&message).is_ok()
+        }))
+    }
+}
+
+struct OpenDeepLink {}
+
+impl CommandHandler for OpenDeepLink {
+    fn handle_command(_api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let link: &String = matches.get_one::<String>("link").unwrap();
+
+        if DeepLink::parse(link).is_none() {
+            return Err(APIError::Validation(vec![FieldError {
+                field: String::from("link"),
+                message: String::from("Not a valid frogworks:// link.")
+            }]));
+        }
+
+        let mut client: DaemonClient = DaemonClient::new();
+
+        // Start the daemon if it isn't already running.
+        if let Some(executable) = matches.get_one::<PathBuf>("spawn") {
+            client = client.with_spawn(executable.clone());
+        }
+
+        let response: DaemonResponse = client.send(&DaemonMessage::new("args", json!([link])))?;
+
+        Ok(to_value(response)?)
     }
 }
 
 struct AuthenticateSession {}
 
 impl CommandHandler for AuthenticateSession {
-    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> Value {
-        to_value(api_service.authenticate_session().unwrap()).unwrap()
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(to_value(api_service.authenticate_session()?)?)
     }
 }
 
 struct DeleteSession {}
 
 impl CommandHandler for DeleteSession {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
-        let result = if let Some(session_id) = matches.get_one::<i32>("session-id") {
-            api_service.delete_specific_session(session_id.to_owned())
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let deleted: bool = if let Some(session_id) = matches.get_one::<i32>("session-id") {
+            api_service.delete_specific_session(session_id.to_owned())?
         } else { 
-            api_service.delete_session()
+            api_service.delete_session()?
         };
         
-        if result.is_ok() {
-            Bool(true)
-        } else { 
-            Bool(false)
-        }
+        Ok(json!({
+            "success": true,
+            "deleted": deleted
+        }))
+    }
+}
+
+struct DeleteOtherSessions {}
+
+impl CommandHandler for DeleteOtherSessions {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(json!({
+            "deleted": api_service.delete_other_sessions()?
+        }))
     }
 }
 
 struct Login {}
 
 impl CommandHandler for Login {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let username: String = matches.get_one::<String>("username").unwrap().to_owned();
-        let password: String = matches.get_one::<String>("password").unwrap().to_owned();
+        let password: String = read_password(matches);
         
         // Logging in will get the session id.
-        let session_id: String = api_service.login(username, password).unwrap();
+        let session_id: String = api_service.login(username, password)?;
         
-        json!({"session_id": session_id})
+        Ok(json!({"session_id": session_id}))
     }
 }
 
 struct Register {}
 
 impl CommandHandler for Register {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let username: String = matches.get_one::<String>("username").unwrap().to_owned();
         let name: String = matches.get_one::<String>("name").unwrap().to_owned();
         let email_address: String = matches.get_one::<String>("email-address")
             .unwrap()
             .to_owned();
-        let password: String = matches.get_one::<String>("password").unwrap().to_owned();
+        let password: String = read_password(matches);
         let email_verification_code: i32 = matches.get_one::<i32>("email-verification-code")
             .unwrap()
             .to_owned();
@@ -81,29 +194,115 @@ impl CommandHandler for Register {
             email_address,
             password,
             email_verification_code
-        ).unwrap()
+        )
+    }
+}
+
+struct CheckAvailability {}
+
+impl CommandHandler for CheckAvailability {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let mut result: Value = json!({});
+
+        if let Some(username) = matches.get_one::<String>("username") {
+            result["username_available"] =
+                Bool(api_service.check_username_available(username.to_owned())?);
+        }
+
+        if let Some(email_address) = matches.get_one::<String>("email-address") {
+            result["email_address_available"] =
+                Bool(api_service.check_email_available(email_address.to_owned())?);
+        }
+
+        Ok(result)
+    }
+}
+
+struct ExportAccountData {}
+
+impl CommandHandler for ExportAccountData {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let dest: PathBuf = matches.get_one::<PathBuf>("dest")
+            .unwrap()
+            .to_owned();
+        
+        let filepath: PathBuf = api_service.export_account_data(dest)?;
+        
+        Ok(json!({
+            "path": filepath
+        }))
+    }
+}
+
+struct RequestEmailChange {}
+
+impl CommandHandler for RequestEmailChange {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let email_address: String = matches.get_one::<String>("email-address")
+            .unwrap()
+            .to_owned();
+        
+        api_service.request_email_change(email_address)?;
+        
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct ConfirmEmailChange {}
+
+impl CommandHandler for ConfirmEmailChange {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameters.
+        let email_address: String = matches.get_one::<String>("email-address")
+            .unwrap()
+            .to_owned();
+        let verification_code: i32 = matches.get_one::<i32>("verification-code")
+            .unwrap()
+            .to_owned();
+        
+        api_service.confirm_email_change(email_address, verification_code)?;
+        
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct RequestDeveloperStatus {}
+
+impl CommandHandler for RequestDeveloperStatus {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        api_service.request_developer_status()?;
+        
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct RequestEmailVerification {}
 
 impl CommandHandler for RequestEmailVerification {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let email_address: String = matches.get_one::<String>("email-address")
             .unwrap()
             .to_owned();
         
-        api_service.request_email_verification(email_address).unwrap();
+        api_service.request_email_verification(email_address)?;
         
-        json!({"success": true})
+        Ok(json!({"success": true}))
     }
 }
 
 struct CheckEmailVerification {}
 
 impl CommandHandler for CheckEmailVerification {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let email_address: String = matches.get_one::<String>("email-address")
             .unwrap()
@@ -112,19 +311,19 @@ impl CommandHandler for CheckEmailVerification {
             .unwrap()
             .to_owned();
         
-        let verified = api_service.check_email_verification(
+        let status = api_service.check_email_verification(
             email_address,
             verification_code
-        ).unwrap();
+        )?;
         
-        json!({"success": verified})
+        Ok(json!({"success": status.is_verified(), "status": status}))
     }
 }
 
 struct GetUser {}
 
 impl CommandHandler for GetUser {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let identifier: String = matches.get_one::<String>("identifier")
             .unwrap()
@@ -133,14 +332,83 @@ impl CommandHandler for GetUser {
             .unwrap()
             .to_owned();
         
-        to_value(api_service.get_user(identifier, identifier_type).unwrap()).unwrap()
+        Ok(to_value(api_service.get_user(identifier, identifier_type)?)?)
+    }
+}
+
+struct GetPublicProfile {}
+
+impl CommandHandler for GetPublicProfile {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        
+        Ok(to_value(api_service.get_public_profile(user_id)?)?)
+    }
+}
+
+struct GetProfilePhoto {}
+
+impl CommandHandler for GetProfilePhoto {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameters.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        let dest: PathBuf = matches.get_one::<PathBuf>("dest")
+            .unwrap()
+            .to_owned();
+
+        Ok(json!({
+            "path": api_service.get_profile_photo(user_id, dest)?
+        }))
+    }
+}
+
+struct GetDashboard {}
+
+impl CommandHandler for GetDashboard {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        
+        Ok(to_value(api_service.get_dashboard(user_id)?)?)
+    }
+}
+
+struct GetMe {}
+
+impl CommandHandler for GetMe {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(to_value(api_service.get_me()?)?)
+    }
+}
+
+struct GetBalance {}
+
+impl CommandHandler for GetBalance {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        
+        let balance: f32 = api_service.get_balance(user_id)?;
+        
+        Ok(json!({
+            "balance": balance
+        }))
     }
 }
 
 struct CreateApplication {}
 
 impl CommandHandler for CreateApplication {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let name: String = matches.get_one::<String>("name")
             .unwrap()
@@ -173,16 +441,10 @@ impl CommandHandler for CreateApplication {
             .unwrap()
             .to_owned();
         
-        // Parse each string list into a Vec<String>.
-        let supported_platforms: Vec<String> = supported_platforms_string.split(",")
-            .map(|s| s.to_owned())
-            .collect();
-        let genres: Vec<String> = genres_string.split(",")
-            .map(|s| s.to_owned())
-            .collect();
-        let tags: Vec<String> = tags_string.split(",")
-            .map(|s| s.to_owned())
-            .collect();
+        // Parse each string list into a Vec<String> (a "\," is a comma within a value).
+        let supported_platforms: Vec<String> = decode_list(&supported_platforms_string);
+        let genres: Vec<String> = decode_list(&genres_string);
+        let tags: Vec<String> = decode_list(&tags_string);
         
         // Attempt to create the application.
         let response = api_service.create_application(
@@ -196,32 +458,243 @@ impl CommandHandler for CreateApplication {
             genres,
             tags,
             base_price
-        ).unwrap();
+        )?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
+    }
+}
+
+struct ImportApplications {}
+
+impl CommandHandler for ImportApplications {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let file: PathBuf = matches.get_one::<PathBuf>("file")
+            .unwrap()
+            .to_owned();
+
+        // Parse the entries one at a time, so an invalid entry doesn't stop the import.
+        let entries: Vec<Value> = from_str(&read_to_string(file)?)?;
+        let mut results: Vec<Value> = Vec::new();
+        let mut imported: usize = 0;
+
+        for (index, entry) in entries.into_iter().enumerate() {
+            let result = from_value::<ApplicationDefinition>(entry)
+                .map_err(APIError::from)
+                .and_then(|definition| {
+                    let package_name: String = definition.package_name.clone();
+
+                    api_service.create_application(
+                        definition.name,
+                        definition.package_name,
+                        definition.application_type,
+                        definition.description,
+                        definition.release_date,
+                        definition.early_access,
+                        definition.supported_platforms,
+                        definition.genres,
+                        definition.tags,
+                        definition.base_price
+                    ).map(|response| (package_name, response))
+                });
+
+            match result {
+                Ok((package_name, response)) => {
+                    imported += 1;
+
+                    results.push(json!({
+                        "index": index,
+                        "package_name": package_name,
+                        "success": true,
+                        "response": to_value(response)?
+                    }));
+                },
+                Err(err) => {
+                    results.push(json!({
+                        "index": index,
+                        "success": false,
+                        "error": err.to_string()
+                    }));
+                }
+            }
+        }
+
+        Ok(json!({
+            "imported": imported,
+            "failed": results.len() - imported,
+            "results": results
+        }))
+    }
+}
+
+struct ExportCatalog {}
+
+impl CommandHandler for ExportCatalog {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        let file: PathBuf = matches.get_one::<PathBuf>("file")
+            .unwrap()
+            .to_owned();
+
+        let definitions = api_service.export_catalog(user_id)?;
+
+        write(&file, to_string_pretty(&definitions)?)?;
+
+        Ok(json!({
+            "success": true,
+            "exported": definitions.len(),
+            "file": file
+        }))
     }
 }
 
 struct GetApplication {}
 
 impl CommandHandler for GetApplication {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
         
         // Attempt to get the application.
-        let application = api_service.get_application(application_id).unwrap();
+        let application = api_service.get_application(application_id)?;
+        
+        Ok(to_value(application)?)
+    }
+}
+
+struct GetApplications {}
+
+impl CommandHandler for GetApplications {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter (clap splits and parses the comma-separated ids).
+        let application_ids: Vec<i32> = matches.get_many::<i32>("application-ids")
+            .unwrap()
+            .copied()
+            .collect();
+        
+        // Attempt to get the applications.
+        let applications = api_service.get_applications(application_ids)?;
+        
+        Ok(to_value(applications)?)
+    }
+}
+
+struct GetAllGenres {}
+
+impl CommandHandler for GetAllGenres {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(to_value(api_service.get_all_genres()?)?)
+    }
+}
+
+struct GetAllTags {}
+
+impl CommandHandler for GetAllTags {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        Ok(to_value(api_service.get_all_tags()?)?)
+    }
+}
+
+struct GetTrendingApplications {}
+
+impl CommandHandler for GetTrendingApplications {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let limit: u32 = matches.get_one::<u32>("limit")
+            .unwrap()
+            .to_owned();
+        
+        Ok(to_value(api_service.get_trending_applications(limit)?)?)
+    }
+}
+
+struct GetNewReleases {}
+
+impl CommandHandler for GetNewReleases {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let limit: u32 = matches.get_one::<u32>("limit")
+            .unwrap()
+            .to_owned();
+        
+        Ok(to_value(api_service.get_new_releases(limit)?)?)
+    }
+}
+
+struct GetTotalPlaytime {}
+
+impl CommandHandler for GetTotalPlaytime {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+
+        Ok(json!({
+            "total_seconds": api_service.get_total_playtime(user_id, application_id)?
+        }))
+    }
+}
+
+struct GetApplicationRevenue {}
+
+impl CommandHandler for GetApplicationRevenue {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameters.
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+        let start: String = matches.get_one::<String>("start")
+            .unwrap()
+            .to_owned();
+        let end: String = matches.get_one::<String>("end")
+            .unwrap()
+            .to_owned();
+        
+        let report = api_service.get_application_revenue(application_id, start, end)?;
         
-        to_value(application).unwrap()
+        Ok(to_value(report)?)
+    }
+}
+
+struct GetDeveloperApplications {}
+
+impl CommandHandler for GetDeveloperApplications {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        
+        let applications = api_service.get_developer_applications(user_id)?;
+        
+        Ok(to_value(applications)?)
+    }
+}
+
+struct GetAvailablePlatforms {}
+
+impl CommandHandler for GetAvailablePlatforms {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+
+        let platforms = api_service.get_available_platforms(application_id)?;
+
+        Ok(to_value(platforms)?)
     }
 }
 
 struct GetApplicationVersions {}
 
 impl CommandHandler for GetApplicationVersions {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
@@ -235,30 +708,53 @@ impl CommandHandler for GetApplicationVersions {
             .get_application_versions(application_id, platform)
             .unwrap();
         
-        to_value(application_versions).unwrap()
+        Ok(to_value(application_versions)?)
     }
 }
 
 struct GetSpecificApplicationVersion {}
 
 impl CommandHandler for GetSpecificApplicationVersion {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let version_id: i32 = matches.get_one::<i32>("version-id")
             .unwrap()
             .to_owned();
         
         let application_version = api_service.get_application_version(version_id)
-            .unwrap();
+            ?;
         
-        to_value(application_version).unwrap()
+        Ok(to_value(application_version)?)
+    }
+}
+
+struct DownloadApplicationVersion {}
+
+impl CommandHandler for DownloadApplicationVersion {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameters.
+        let version_id: i32 = matches.get_one::<i32>("version-id")
+            .unwrap()
+            .to_owned();
+        let dest: String = matches.get_one::<String>("dest")
+            .unwrap()
+            .to_owned();
+        let collision_policy: CollisionPolicy = if matches.get_flag("keep-existing") {
+            CollisionPolicy::Rename
+        } else {
+            CollisionPolicy::Overwrite
+        };
+
+        Ok(json!({
+            "path": api_service.download_application_version(version_id, dest, collision_policy)?
+        }))
     }
 }
 
 struct GetApplicationVersionFor {}
 
 impl CommandHandler for GetApplicationVersionFor {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
@@ -271,16 +767,16 @@ impl CommandHandler for GetApplicationVersionFor {
         
         let application_version = 
             api_service.get_application_version(application_id)
-                .unwrap();
+                ?;
         
-        to_value(application_version).unwrap()
+        Ok(to_value(application_version)?)
     }
 }
 
 struct GetFineTunedApplicationVersion {}
 
 impl CommandHandler for GetFineTunedApplicationVersion {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
@@ -294,20 +790,26 @@ impl CommandHandler for GetFineTunedApplicationVersion {
             .unwrap()
             .to_owned();
         
+        let architecture: Option<String> = matches.get_one::<String>("architecture")
+            .cloned();
+        let branch: Option<String> = matches.get_one::<String>("branch").cloned();
+        
         let version = api_service.get_application_version_for(
             application_id,
             version_name,
-            platform
-        ).unwrap();
+            platform,
+            architecture,
+            branch
+        )?;
         
-        to_value(version).unwrap()
+        Ok(to_value(version)?)
     }
 }
 
 struct UpdateApplicationVersion {}
 
 impl CommandHandler for UpdateApplicationVersion {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
@@ -318,19 +820,78 @@ impl CommandHandler for UpdateApplicationVersion {
             .to_owned();
         
         // Update the application version.
-        let response = 
-            api_service.update_application_version(application_id, version_name);
+        api_service.update_application_version(application_id, version_name)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct SetUserBranch {}
+
+impl CommandHandler for SetUserBranch {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameters.
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+        let branch: String = matches.get_one::<String>("branch")
+            .unwrap()
+            .to_owned();
+        
+        api_service.set_user_branch(application_id, branch)?;
+        
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct GetUserBranch {}
+
+impl CommandHandler for GetUserBranch {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+        
+        let branch: String = api_service.get_user_branch(application_id)?;
+        
+        Ok(json!({
+            "branch": branch
+        }))
+    }
+}
+
+struct SetBranchVersion {}
+
+impl CommandHandler for SetBranchVersion {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameters.
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+        let branch: String = matches.get_one::<String>("branch")
+            .unwrap()
+            .to_owned();
+        let version_name: String = matches.get_one::<String>("version-name")
+            .unwrap()
+            .to_owned();
+        
+        api_service.set_branch_version(application_id, branch, version_name)?;
+        
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct CreateApplicationVersion {}
 
 impl CommandHandler for CreateApplicationVersion {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
@@ -354,7 +915,7 @@ impl CommandHandler for CreateApplicationVersion {
             .unwrap()
             .to_owned();
         
-        let response = api_service.create_application_version(
+        let mut version: NewApplicationVersion = NewApplicationVersion::new(
             application_id,
             name,
             platform,
@@ -364,16 +925,41 @@ impl CommandHandler for CreateApplicationVersion {
             filepath
         );
         
-        json!({
-            "success": response.is_ok()
-        })
+        if let Some(branch) = matches.get_one::<String>("branch") {
+            version = version.branch(branch.to_owned());
+        }
+        
+        if let Some(changelog) = matches.get_one::<String>("changelog") {
+            version = version.changelog(changelog.to_owned());
+        }
+        
+        api_service.create_application_version(version)?;
+        
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct GetVersionChangelog {}
+
+impl CommandHandler for GetVersionChangelog {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let version_id: i32 = matches.get_one::<i32>("version-id")
+            .unwrap()
+            .to_owned();
+
+        Ok(json!({
+            "changelog": api_service.get_version_changelog(version_id)?
+        }))
     }
 }
 
 struct CreateSale {}
 
 impl CommandHandler for CreateSale {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters. 
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
@@ -394,191 +980,357 @@ impl CommandHandler for CreateSale {
             .unwrap()
             .to_owned();
         
-        let response = api_service.create_sale(
+        api_service.create_sale(
             application_id,
             title,
             description,
             price,
             start_date,
             end_date
-        );
+        )?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetActiveSale {}
 
 impl CommandHandler for GetActiveSale {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
         
-        let active_sale = api_service.get_active_sale(application_id).unwrap();
+        let active_sale = api_service.get_active_sale(application_id)?;
         
-        to_value(active_sale).unwrap()
+        Ok(to_value(active_sale)?)
     }
 }
 
 struct GetAllSales {}
 
 impl CommandHandler for GetAllSales {
-    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> Value {
-        let sales = api_service.get_all_sales().unwrap();
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        let sales = api_service.get_all_sales()?;
+        
+        Ok(to_value(sales)?)
+    }
+}
+
+struct GetDeveloperSales {}
+
+impl CommandHandler for GetDeveloperSales {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        
+        let sales = api_service.get_developer_sales(user_id)?;
+        
+        Ok(to_value(sales)?)
+    }
+}
+
+struct GetApplicationsOnSale {}
+
+impl CommandHandler for GetApplicationsOnSale {
+    fn handle_command(api_service: ApiService, _matches: &ArgMatches) -> ApiResult<Value> {
+        let applications_on_sale = api_service.get_applications_on_sale()?;
         
-        to_value(sales).unwrap()
+        Ok(to_value(applications_on_sale)?)
     }
 }
 
 struct DeleteSale {}
 
 impl CommandHandler for DeleteSale {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let sale_id: i32 = matches.get_one::<i32>("sale-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.delete_sale(sale_id);
+        api_service.delete_sale(sale_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetUserTransactions {}
 
 impl CommandHandler for GetUserTransactions {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_user_transactions(user_id).unwrap();
+        let response = api_service.get_user_transactions(user_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct GetTransaction {}
 
 impl CommandHandler for GetTransaction {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let transaction_id: i32 = matches.get_one::<i32>("transaction-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_transaction(transaction_id).unwrap();
+        let response = api_service.get_transaction(transaction_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct GetPurchase {}
 
 impl CommandHandler for GetPurchase {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let purchase_id: i32 = matches.get_one::<i32>("purchase-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_purchase(purchase_id).unwrap();
+        let response = api_service.get_purchase(purchase_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
+    }
+}
+
+struct GetPurchaseReceipt {}
+
+impl CommandHandler for GetPurchaseReceipt {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let purchase_id: i32 = matches.get_one::<i32>("purchase-id")
+            .unwrap()
+            .to_owned();
+
+        let receipt = api_service.get_purchase_receipt(purchase_id)?;
+
+        if let Some(file) = matches.get_one::<PathBuf>("file") {
+            receipt.save(file)?;
+        }
+
+        Ok(json!({
+            "text": receipt.to_text(),
+            "receipt": receipt
+        }))
+    }
+}
+
+struct GetUserPurchases {}
+
+impl CommandHandler for GetUserPurchases {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        
+        let response = api_service.get_user_purchases(user_id)?;
+        
+        Ok(to_value(response)?)
+    }
+}
+
+struct GetDeposit {}
+
+impl CommandHandler for GetDeposit {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let deposit_id: i32 = matches.get_one::<i32>("deposit-id")
+            .unwrap()
+            .to_owned();
+        
+        let response = api_service.get_deposit(deposit_id)?;
+        
+        Ok(to_value(response)?)
+    }
+}
+
+struct GetApplicationKey {}
+
+impl CommandHandler for GetApplicationKey {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let key: String = matches.get_one::<String>("key")
+            .unwrap()
+            .to_owned();
+        
+        let response = api_service.get_application_key(key)?;
+        
+        Ok(to_value(response)?)
+    }
+}
+
+struct ResolveApplicationKey {}
+
+impl CommandHandler for ResolveApplicationKey {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let key: String = matches.get_one::<String>("key")
+            .unwrap()
+            .to_owned();
+
+        let (application_key, application) = api_service.resolve_application_key(key)?;
+
+        Ok(json!({
+            "key": application_key,
+            "application": application
+        }))
+    }
+}
+
+struct GetUserApplicationKeys {}
+
+impl CommandHandler for GetUserApplicationKeys {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        
+        let response = api_service.get_user_application_keys(user_id)?;
+        
+        Ok(to_value(response)?)
+    }
+}
+
+struct RevokeApplicationKey {}
+
+impl CommandHandler for RevokeApplicationKey {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameter.
+        let key: String = matches.get_one::<String>("key")
+            .unwrap()
+            .to_owned();
+        
+        api_service.revoke_application_key(key)?;
+        
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct ExtendApplicationKey {}
+
+impl CommandHandler for ExtendApplicationKey {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let key: String = matches.get_one::<String>("key")
+            .unwrap()
+            .to_owned();
+        let days: u32 = matches.get_one::<u32>("days")
+            .unwrap()
+            .to_owned();
+
+        let application_key = api_service.extend_application_key(key, days)?;
+
+        Ok(to_value(application_key)?)
     }
 }
 
-struct GetDeposit {}
+struct RevokeApplicationKeys {}
 
-impl CommandHandler for GetDeposit {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+impl CommandHandler for RevokeApplicationKeys {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
-        let deposit_id: i32 = matches.get_one::<i32>("deposit-id")
+        let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_deposit(deposit_id).unwrap();
+        let revoked: u64 = api_service.revoke_application_keys(application_id)?;
         
-        to_value(response).unwrap()
+        Ok(json!({
+            "revoked": revoked
+        }))
     }
 }
 
-struct GetApplicationKey {}
+struct PurchaseApplication {}
 
-impl CommandHandler for GetApplicationKey {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+impl CommandHandler for PurchaseApplication {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
-        let key: String = matches.get_one::<String>("key")
+        let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_application_key(key).unwrap();
+        api_service.purchase_application(application_id, None)?;
         
-        to_value(response).unwrap()
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
-struct GetUserApplicationKeys {}
+struct PreorderApplication {}
 
-impl CommandHandler for GetUserApplicationKeys {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+impl CommandHandler for PreorderApplication {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
-        let user_id: i32 = matches.get_one::<i32>("user-id")
+        let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_user_application_keys(user_id).unwrap();
+        api_service.preorder_application(application_id, None)?;
         
-        to_value(response).unwrap()
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
-struct PurchaseApplication {}
+struct TransferBalance {}
 
-impl CommandHandler for PurchaseApplication {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
-        // Get the parameter.
-        let application_id: i32 = matches.get_one::<i32>("application-id")
+impl CommandHandler for TransferBalance {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        // Get the parameters.
+        let recipient_user_id: i32 = matches.get_one::<i32>("recipient-user-id")
             .unwrap()
             .to_owned();
-        
-        let response = api_service.purchase_application(application_id);
-        
-        json!({
-            "success": response.is_ok()
-        })
+        let amount: f32 = matches.get_one::<f32>("amount")
+            .unwrap()
+            .to_owned();
+
+        // Re-running the command with the same key won't transfer the funds twice.
+        let idempotency_key: Option<String> = matches.get_one::<String>("idempotency-key").cloned();
+
+        Ok(to_value(api_service.transfer_balance(recipient_user_id, amount, idempotency_key)?)?)
     }
 }
 
 struct PurchaseIap {}
 
 impl CommandHandler for PurchaseIap {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let iap_id: i32 = matches.get_one::<i32>("iap-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.purchase_iap(iap_id);
+        api_service.purchase_iap(iap_id, None)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetIapRecords {}
 
 impl CommandHandler for GetIapRecords {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameters.
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
@@ -592,142 +1344,211 @@ impl CommandHandler for GetIapRecords {
             user_id,
             application_id,
             only_unacknowledged
-        ).unwrap();
+        )?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
+    }
+}
+
+struct CountUnacknowledgedIapRecords {}
+
+impl CommandHandler for CountUnacknowledgedIapRecords {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+
+        Ok(json!({
+            "count": api_service.count_unacknowledged_iap_records(user_id, application_id)?
+        }))
     }
 }
 
 struct GetSession {}
 
 impl CommandHandler for GetSession {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let session_id: String = matches.get_one::<String>("session-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_session(session_id).unwrap();
+        let response = api_service.get_session(session_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct SendFriendRequest {}
 
 impl CommandHandler for SendFriendRequest {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.send_friend_request(user_id);
+        api_service.send_friend_request(user_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct DeleteFriendRequest {}
 
 impl CommandHandler for DeleteFriendRequest {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let request_id: i32 = matches.get_one::<i32>("request-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.delete_friend_request(request_id);
+        api_service.delete_friend_request(request_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetIncomingFriendRequests {}
 
 impl CommandHandler for GetIncomingFriendRequests {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_incoming_friend_requests(user_id).unwrap();
+        let response = api_service.get_incoming_friend_requests(user_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct GetOutgoingFriendRequests {}
 
 impl CommandHandler for GetOutgoingFriendRequests {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_outgoing_friend_requests(user_id).unwrap();
+        let response = api_service.get_outgoing_friend_requests(user_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct AcceptFriendRequest {}
 
 impl CommandHandler for AcceptFriendRequest {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         // Get the parameter.
         let request_id: i32 = matches.get_one::<i32>("request-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.accept_friend_request(request_id);
+        api_service.accept_friend_request(request_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetFriends {}
 
 impl CommandHandler for GetFriends {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_friends(user_id).unwrap();
+        if matches.get_flag("online-first") || matches.get_flag("online-only") {
+            let online_only: bool = matches.get_flag("online-only");
+
+            return Ok(to_value(api_service.get_friends_by_presence(user_id, online_only)?)?);
+        }
+
+        let response = api_service.get_friends(user_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct RemoveFriend {}
 
 impl CommandHandler for RemoveFriend {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.remove_friend(user_id);
+        api_service.remove_friend(user_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct FollowDeveloper {}
+
+impl CommandHandler for FollowDeveloper {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let developer_user_id: i32 = matches.get_one::<i32>("developer-user-id")
+            .unwrap()
+            .to_owned();
+
+        api_service.follow_developer(developer_user_id)?;
+
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct UnfollowDeveloper {}
+
+impl CommandHandler for UnfollowDeveloper {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let developer_user_id: i32 = matches.get_one::<i32>("developer-user-id")
+            .unwrap()
+            .to_owned();
+
+        api_service.unfollow_developer(developer_user_id)?;
+
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct GetFollowedDevelopers {}
+
+impl CommandHandler for GetFollowedDevelopers {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+
+        let developers = api_service.get_followed_developers(user_id)?;
+
+        Ok(to_value(developers)?)
     }
 }
 
 struct SendInvite {}
 
 impl CommandHandler for SendInvite {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
@@ -738,62 +1559,126 @@ impl CommandHandler for SendInvite {
             .unwrap()
             .to_owned();
         
-        let response = api_service.send_invite(user_id, application_id, details);
+        api_service.send_invite(user_id, application_id, details)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetInvites {}
 
 impl CommandHandler for GetInvites {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_invites(user_id).unwrap();
+        let response = api_service.get_invites(user_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct GetInvite {}
 
 impl CommandHandler for GetInvite {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let invite_id: i32 = matches.get_one::<i32>("invite-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_invites(invite_id).unwrap();
+        let response = api_service.get_invites(invite_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
+    }
+}
+
+struct AcceptInvite {}
+
+impl CommandHandler for AcceptInvite {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let invite_id: i32 = matches.get_one::<i32>("invite-id")
+            .unwrap()
+            .to_owned();
+        
+        let response = api_service.accept_invite(invite_id)?;
+        
+        Ok(to_value(response)?)
+    }
+}
+
+struct DeclineInvite {}
+
+impl CommandHandler for DeclineInvite {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let invite_id: i32 = matches.get_one::<i32>("invite-id")
+            .unwrap()
+            .to_owned();
+        
+        api_service.decline_invite(invite_id)?;
+        
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct DeleteInvite {}
 
 impl CommandHandler for DeleteInvite {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let invite_id: i32 = matches.get_one::<i32>("invite-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.delete_invite(invite_id);
+        api_service.delete_invite(invite_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct AddApplicationMedia {}
+
+impl CommandHandler for AddApplicationMedia {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+        let media_type: String = matches.get_one::<String>("media-type")
+            .unwrap()
+            .to_owned();
+        let filepath: String = matches.get_one::<String>("filepath")
+            .unwrap()
+            .to_owned();
+
+        api_service.add_application_media(application_id, media_type, filepath)?;
+
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct GetApplicationMedia {}
+
+impl CommandHandler for GetApplicationMedia {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+
+        Ok(to_value(api_service.get_application_media(application_id)?)?)
     }
 }
 
 struct CreatePhoto {}
 
 impl CommandHandler for CreatePhoto {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let subfolder: String = matches.get_one::<String>("subfolder")
             .unwrap()
             .to_owned();
@@ -801,32 +1686,32 @@ impl CommandHandler for CreatePhoto {
             .unwrap()
             .to_owned();
         
-        let result = api_service.create_photo(subfolder, filepath);
+        api_service.create_photo(subfolder, filepath)?;
         
-        json!({
-            "success": result.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetPhoto {}
 
 impl CommandHandler for GetPhoto {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let id: i32 = matches.get_one::<i32>("id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_photo(id).unwrap();
+        let response = api_service.get_photo(id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct CreateIap {}
 
 impl CommandHandler for CreateIap {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
@@ -843,68 +1728,79 @@ impl CommandHandler for CreateIap {
             .unwrap()
             .to_owned();
         
-        let result = api_service.create_iap(application_id, title, description, price, data);
+        api_service.create_iap(application_id, title, description, price, data)?;
         
-        json!({
-            "success": result.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetIap {}
 
 impl CommandHandler for GetIap {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let id: i32 = matches.get_one::<i32>("id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_iap(id).unwrap();
+        let response = api_service.get_iap(id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct GetIaps {}
 
 impl CommandHandler for GetIaps {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_iaps(application_id).unwrap();
+        let response = api_service.get_iaps(application_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct UploadCloudData {}
 
 impl CommandHandler for UploadCloudData {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
-        let data: String = matches.get_one::<String>("data")
-            .unwrap()
-            .to_owned();
-        
-        let result = api_service.upload_cloud_data(user_id, application_id, data);
+        let slot: Option<String> = matches.get_one::<String>("slot").cloned();
+        let base_date: Option<String> = matches.get_one::<String>("base-date").cloned();
+
+        // Large saves are streamed from a file rather than passed on the command line.
+        match matches.get_one::<PathBuf>("file") {
+            Some(file) => api_service.upload_cloud_data_from_file(user_id, application_id,
+                                                                  file.to_owned(), slot,
+                                                                  base_date)?,
+            None => {
+                let data: String = matches.get_one::<String>("data")
+                    .unwrap()
+                    .to_owned();
+
+                api_service.upload_cloud_data(user_id, application_id, data, slot, base_date)?
+            }
+        }
         
-        json!({
-            "success": result.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetCloudData {}
 
 impl CommandHandler for GetCloudData {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
@@ -912,51 +1808,98 @@ impl CommandHandler for GetCloudData {
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_cloud_data(user_id, application_id).unwrap();
+        let slot: Option<String> = matches.get_one::<String>("slot").cloned();
         
-        to_value(response).unwrap()
+        let response = api_service.get_cloud_data(user_id, application_id, slot)?;
+        
+        Ok(to_value(response)?)
     }
 }
 
 struct DeleteCloudData {}
 
 impl CommandHandler for DeleteCloudData {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+        
+        let slot: Option<String> = matches.get_one::<String>("slot").cloned();
+        
+        api_service.delete_cloud_data(user_id, application_id, slot)?;
+        
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct ListCloudDataSlots {}
+
+impl CommandHandler for ListCloudDataSlots {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let user_id: i32 = matches.get_one::<i32>("user-id")
+            .unwrap()
+            .to_owned();
+        let application_id: i32 = matches.get_one::<i32>("application-id")
+            .unwrap()
+            .to_owned();
+        
+        let response = api_service.list_cloud_data_slots(user_id, application_id)?;
+        
+        Ok(to_value(response)?)
+    }
+}
+
+struct RenameCloudDataSlot {}
+
+impl CommandHandler for RenameCloudDataSlot {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
+        let slot: String = matches.get_one::<String>("slot")
+            .unwrap()
+            .to_owned();
+        let new_slot: String = matches.get_one::<String>("new-slot")
+            .unwrap()
+            .to_owned();
         
-        let response = api_service.delete_cloud_data(user_id, application_id);
+        api_service.rename_cloud_data_slot(user_id, application_id, slot,
+                                                          new_slot)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct DeleteApplicationCloudData {}
 
 impl CommandHandler for DeleteApplicationCloudData {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let application_id: i32 = matches.get_one::<i32>("application-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.delete_application_cloud_data(application_id);
+        api_service.delete_application_cloud_data(application_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct UpdateProfilePhoto {}
 
 impl CommandHandler for UpdateProfilePhoto {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
@@ -964,55 +1907,69 @@ impl CommandHandler for UpdateProfilePhoto {
             .unwrap()
             .to_owned();
         
-        let response = api_service.update_profile_photo(user_id, photo_id);
+        api_service.update_profile_photo(user_id, photo_id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
+    }
+}
+
+struct SetStatusMessage {}
+
+impl CommandHandler for SetStatusMessage {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
+        let message: Option<String> = matches.get_one::<String>("message").cloned();
+
+        api_service.set_status_message(message)?;
+
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
 struct GetUserSessions {}
 
 impl CommandHandler for GetUserSessions {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let user_id: i32 = matches.get_one::<i32>("user-id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_user_sessions(user_id).unwrap();
+        let response = api_service.get_user_sessions(user_id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct GetIapRecord {}
 
 impl CommandHandler for GetIapRecord {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let id: i32 = matches.get_one::<i32>("id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.get_iap_record(id).unwrap();
+        let response = api_service.get_iap_record(id)?;
         
-        to_value(response).unwrap()
+        Ok(to_value(response)?)
     }
 }
 
 struct AcknowledgeIapRecord {}
 
 impl CommandHandler for AcknowledgeIapRecord {
-    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> Value {
+    fn handle_command(api_service: ApiService, matches: &ArgMatches) -> ApiResult<Value> {
         let id: i32 = matches.get_one::<i32>("id")
             .unwrap()
             .to_owned();
         
-        let response = api_service.acknowledge_iap_record(id);
+        api_service.acknowledge_iap_record(id)?;
         
-        json!({
-            "success": response.is_ok()
-        })
+        Ok(json!({
+            "success": true
+        }))
     }
 }
 
@@ -1022,13 +1979,13 @@ struct JsonResponse<T> {
     response: T
 }
 
-fn timed_response<T, F>(request_logic: F) -> Value
+fn timed_response<T, F>(request_logic: F) -> ApiResult<Value>
 where 
-    F: FnOnce() -> T,
+    F: FnOnce() -> ApiResult<T>,
     T: Serialize,
 {
     let start: Instant = Instant::now();
-    let response: T = request_logic();
+    let response: T = request_logic()?;
     let duration: Duration = start.elapsed();
     
     let json_response = JsonResponse {
@@ -1036,27 +1993,120 @@ where
         response
     };
     
-    to_value(&json_response).unwrap()
+    Ok(to_value(&json_response)?)
 }
 
 fn handle<T, F>(request_logic: F)
 where
-    F: FnOnce() -> T,
+    F: FnOnce() -> ApiResult<T>,
     T: Serialize
 {
-    let value: Value = timed_response(request_logic);
+    let value: Value = match timed_response(request_logic) {
+        Ok(value) => value,
+        Err(err) => {
+            print_error(&err);
+            
+            exit(exit_code(&err));
+        }
+    };
+    
+    print_json(&value);
+}
+
+/// Get the exit code for an error (see `EXIT_CODES_HELP`).
+fn exit_code(err: &APIError) -> i32 {
+    match err {
+        APIError::BadRequest(_) | APIError::Validation(_) | APIError::Conflict(_) |
+        APIError::InsufficientBalance { .. } | APIError::DuplicatePackageName(_) =>
+            EXIT_BAD_REQUEST,
+        APIError::Unauthorized(_) | APIError::Forbidden(_) => EXIT_UNAUTHORIZED,
+        APIError::NotFound(_) => EXIT_NOT_FOUND,
+        APIError::ReqwestError(_) => EXIT_NETWORK,
+        APIError::Maintenance { .. } | APIError::RateLimited { .. } | APIError::ServerError =>
+            EXIT_UNAVAILABLE,
+        // Most endpoints don't handle these statuses themselves.
+        APIError::UnhandledStatusCode(StatusCode::NOT_FOUND) => EXIT_NOT_FOUND,
+        APIError::UnhandledStatusCode(status_code) if status_code.is_server_error() =>
+            EXIT_UNAVAILABLE,
+        _ => EXIT_ERROR
+    }
+}
+
+/// Get the password for a command, preferring (in order) a line read from stdin when
+/// `--password-stdin` is passed, the `FROGWORKS_PASSWORD` environment variable, and `--password`,
+/// so that passwords needn't end up in shell history or process listings.
+fn read_password(matches: &ArgMatches) -> String {
+    if matches.get_flag("password-stdin") {
+        let mut password: String = String::new();
+        
+        if let Err(err) = io::stdin().lock().read_line(&mut password) {
+            print_error(format!("failed to read the password from stdin: {}", err));
+            
+            exit(EXIT_BAD_REQUEST);
+        }
+        
+        return password.trim_end_matches(['\r', '\n']).to_string();
+    }
     
-    println!("{}", to_string_pretty(&value).unwrap());
+    if let Ok(password) = env::var(PASSWORD_ENV_VAR) {
+        return password;
+    }
+    
+    match matches.get_one::<String>("password") {
+        Some(password) => password.to_owned(),
+        None => {
+            print_error(format!("a password is required (use --password-stdin, {}, or --password).",
+                                PASSWORD_ENV_VAR));
+            
+            exit(EXIT_BAD_REQUEST);
+        }
+    }
+}
+
+/// Report a subcommand that has no handler and exit with a non-zero status. clap rejects unknown
+/// and missing subcommands itself, so this only catches commands that are defined but never
+/// dispatched.
+fn unhandled_subcommand() -> ! {
+    print_error("this subcommand is not handled.");
+    
+    exit(EXIT_BAD_REQUEST);
+}
+
+/// Hand a deep link to the daemon (which handles `frogworks://` links), for when the CLI itself
+/// was opened through the URL scheme.
+fn forward_deep_link(deep_link: DeepLink) -> ApiResult<DaemonResponse> {
+    let message: DaemonMessage = DaemonMessage::new("args", json!([deep_link.to_string()]));
+
+    DaemonClient::new().send(&message)
 }
 
 fn main() {
+    // When opened through a frogworks:// link rather than run directly, the only argument is the
+    // link, so it's forwarded to the daemon instead of being parsed as a subcommand.
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match parse_launch_context(&args) {
+        LaunchContext::DeepLink(deep_link) => {
+            handle(|| forward_deep_link(deep_link));
+
+            return;
+        },
+        LaunchContext::InvalidDeepLink(link) => {
+            print_error(format!("not a valid frogworks:// link: {}", link));
+
+            exit(EXIT_BAD_REQUEST);
+        },
+        LaunchContext::Interactive => {}
+    }
+
     // Debug session ids:
     //  - SlimyFrog123: b5eadd7911364cb98e162acc163a73c1
     //  - DragonMinecart303: d210bd70f62040afa7a78b16d003e89b
-    let command: Command = Command::new(USER_AGENT_STRING)
+    let mut command: Command = Command::new(USER_AGENT_STRING)
         .author("SlimyFrog123")
         .version(APPLICATION_VERSION)
         .about("CLI interface for the Frogworks backend.")
+        .after_help(EXIT_CODES_HELP)
         .subcommand_required(true)
         .arg(
             Arg::new("session-id")
@@ -1064,6 +2114,61 @@ fn main() {
                 .long("session-id")
                 .value_parser(value_parser!(String))
         )
+        .arg(
+            Arg::new("verbose")
+                .help("Print the method, URL, status code, and time taken of each request to stderr.")
+                .long("verbose")
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("no-color")
+                .help("Disable colored output (also disabled by setting NO_COLOR, or when not writing to a terminal).")
+                .long("no-color")
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("timeout")
+                .help("The request timeout, in seconds (0 for no timeout). Defaults to 30 seconds.")
+                .long("timeout")
+                .value_parser(value_parser!(u64))
+        )
+        .subcommand(
+            Command::new("completions")
+                .long_flag("completions")
+                .about("Print a shell completion script.")
+                .arg(
+                    Arg::new("shell")
+                        .long("shell")
+                        .value_parser(value_parser!(Shell))
+                        .required(true)
+                )
+        )
+        .subcommand(
+            Command::new("daemon")
+                .long_flag("daemon")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("status")
+                        .long_flag("status")
+                        .about("Check whether the daemon is running.")
+                )
+                .subcommand(
+                    Command::new("open")
+                        .long_flag("open")
+                        .about("Open a frogworks:// link (install, launch, or store page) in the daemon.")
+                        .arg(
+                            Arg::new("link")
+                                .value_parser(value_parser!(String))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("spawn")
+                                .long("spawn")
+                                .value_parser(value_parser!(PathBuf))
+                                .help("The daemon executable to start if the daemon isn't running.")
+                        )
+                )
+        )
         .subcommand(
             Command::new("server")
                 .long_flag("server")
@@ -1072,6 +2177,21 @@ fn main() {
                     Command::new("ping")
                         .long_flag("ping")
                 )
+                .subcommand(
+                    Command::new("version")
+                        .long_flag("version")
+                        .about("Get the server's API version.")
+                )
+                .subcommand(
+                    Command::new("limits")
+                        .long_flag("limits")
+                        .about("Get the limits the server enforces (upload size, key batch size, and prices).")
+                )
+                .subcommand(
+                    Command::new("diagnose")
+                        .long_flag("diagnose")
+                        .about("Check the connection to the server (URL, DNS, TCP, and ping).")
+                )
         )
         .subcommand(
             Command::new("account")
@@ -1088,9 +2208,16 @@ fn main() {
                         )
                         .arg(
                             Arg::new("password")
+                                .help("Prefer --password-stdin or FROGWORKS_PASSWORD; arguments are visible to other processes.")
                                 .long("password")
                                 .value_parser(value_parser!(String))
-                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("password-stdin")
+                                .help("Read the password from stdin.")
+                                .long("password-stdin")
+                                .action(ArgAction::SetTrue)
+                                .conflicts_with("password")
                         )
                 )
                 .subcommand(
@@ -1116,9 +2243,16 @@ fn main() {
                         )
                         .arg(
                             Arg::new("password")
+                                .help("Prefer --password-stdin or FROGWORKS_PASSWORD; arguments are visible to other processes.")
                                 .long("password")
                                 .value_parser(value_parser!(String))
-                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("password-stdin")
+                                .help("Read the password from stdin.")
+                                .long("password-stdin")
+                                .action(ArgAction::SetTrue)
+                                .conflicts_with("password")
                         )
                         .arg(
                             Arg::new("email-verification-code")
@@ -1127,6 +2261,68 @@ fn main() {
                                 .required(true)
                         )
                 )
+                .subcommand(
+                    Command::new("check-availability")
+                        .long_flag("check-availability")
+                        .about("Check whether a username and/or email address can be registered.")
+                        .arg(
+                            Arg::new("username")
+                                .long("username")
+                                .value_parser(value_parser!(String))
+                                .required_unless_present("email-address")
+                        )
+                        .arg(
+                            Arg::new("email-address")
+                                .long("email-address")
+                                .value_parser(value_parser!(String))
+                        )
+                )
+                .subcommand(
+                    Command::new("export")
+                        .long_flag("export")
+                        .about("Export all of your account's data to an archive.")
+                        .arg(
+                            Arg::new("dest")
+                                .long("dest")
+                                .value_parser(value_parser!(PathBuf))
+                                .default_value(".")
+                        )
+                )
+                .subcommand(
+                    Command::new("change-email")
+                        .long_flag("change-email")
+                        .subcommand_required(true)
+                        .subcommand(
+                            Command::new("request")
+                                .long_flag("request")
+                                .arg(
+                                    Arg::new("email-address")
+                                        .long("email-address")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                        )
+                        .subcommand(
+                            Command::new("confirm")
+                                .long_flag("confirm")
+                                .arg(
+                                    Arg::new("email-address")
+                                        .long("email-address")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("verification-code")
+                                        .long("verification-code")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                        )
+                )
+                .subcommand(
+                    Command::new("request-developer")
+                        .long_flag("request-developer")
+                )
         )
         .subcommand(
             Command::new("email")
@@ -1181,6 +2377,11 @@ fn main() {
                                 .value_parser(value_parser!(i32))
                         )
                 )
+                .subcommand(
+                    Command::new("delete-others")
+                        .long_flag("delete-others")
+                        .about("Sign out of every other device, keeping the current session.")
+                )
                 .subcommand(
                     Command::new("get")
                         .long_flag("get")
@@ -1212,6 +2413,79 @@ fn main() {
                                 .default_value("identifier")
                         )
                 )
+                .subcommand(
+                    Command::new("profile")
+                        .long_flag("profile")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("profile-photo")
+                        .long_flag("profile-photo")
+                        .about("Download a user's profile photo (cached by photo id).")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("dest")
+                                .long("dest")
+                                .value_parser(value_parser!(PathBuf))
+                                .default_value("profile-photos")
+                        )
+                )
+                .subcommand(
+                    Command::new("dashboard")
+                        .long_flag("dashboard")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("me")
+                        .long_flag("me")
+                )
+                .subcommand(
+                    Command::new("follow")
+                        .long_flag("follow")
+                        .about("Follow a developer, to be notified of their new releases")
+                        .arg(
+                            Arg::new("developer-user-id")
+                                .long("developer-user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("unfollow")
+                        .long_flag("unfollow")
+                        .arg(
+                            Arg::new("developer-user-id")
+                                .long("developer-user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("following")
+                        .long_flag("following")
+                        .about("List the developers a user follows")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
                 .subcommand(
                     Command::new("properties")
                         .long_flag("properties")
@@ -1242,6 +2516,33 @@ fn main() {
                                                 .num_args(0)
                                         )
                                 )
+                                .subcommand(
+                                    Command::new("unacknowledged-iap-count")
+                                        .long_flag("unacknowledged-iap-count")
+                                        .about("Count the unacknowledged IAP records (without fetching them)")
+                                        .arg(
+                                            Arg::new("user-id")
+                                                .long("user-id")
+                                                .value_parser(value_parser!(i32))
+                                                .required(true)
+                                        )
+                                        .arg(
+                                            Arg::new("application-id")
+                                                .long("application-id")
+                                                .value_parser(value_parser!(i32))
+                                                .required(true)
+                                        )
+                                )
+                                .subcommand(
+                                    Command::new("balance")
+                                        .long_flag("balance")
+                                        .arg(
+                                            Arg::new("user-id")
+                                                .long("user-id")
+                                                .value_parser(value_parser!(i32))
+                                                .required(true)
+                                        )
+                                )
                                 .subcommand(
                                     Command::new("sessions")
                                         .long_flag("sessions")
@@ -1269,6 +2570,16 @@ fn main() {
                                         .required(true)
                                 )
                         )
+                        .subcommand(
+                            Command::new("set-status")
+                                .long_flag("set-status")
+                                .about("Set the current user's status message (omit --message to clear it)")
+                                .arg(
+                                    Arg::new("message")
+                                        .long("message")
+                                        .value_parser(value_parser!(String))
+                                )
+                        )
                 )
         )
         .subcommand(
@@ -1339,6 +2650,34 @@ fn main() {
                                 .required(true)
                         )
                 )
+                .subcommand(
+                    Command::new("import")
+                        .long_flag("import")
+                        .about("Create each application in a JSON manifest (an array of application definitions), continuing past failures")
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .value_parser(value_parser!(PathBuf))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("export")
+                        .long_flag("export")
+                        .about("Write a developer's applications to a JSON manifest (re-importable with import)")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .value_parser(value_parser!(PathBuf))
+                                .required(true)
+                        )
+                )
                 .subcommand(
                     Command::new("get")
                         .long_flag("get")
@@ -1349,6 +2688,106 @@ fn main() {
                                 .required(true)
                         )
                 )
+                .subcommand(
+                    Command::new("get-many")
+                        .long_flag("get-many")
+                        .arg(
+                            Arg::new("application-ids")
+                                .long("application-ids")
+                                .value_parser(value_parser!(i32))
+                                .value_delimiter(',')
+                                .required(true)
+                                .help("A comma-separated list of application ids (e.g. 1,2,3).")
+                        )
+                )
+                .subcommand(
+                    Command::new("genres")
+                        .long_flag("genres")
+                )
+                .subcommand(
+                    Command::new("tags")
+                        .long_flag("tags")
+                )
+                .subcommand(
+                    Command::new("trending")
+                        .long_flag("trending")
+                        .arg(
+                            Arg::new("limit")
+                                .long("limit")
+                                .value_parser(value_parser!(u32))
+                                .default_value("20")
+                        )
+                )
+                .subcommand(
+                    Command::new("new-releases")
+                        .long_flag("new-releases")
+                        .arg(
+                            Arg::new("limit")
+                                .long("limit")
+                                .value_parser(value_parser!(u32))
+                                .default_value("20")
+                        )
+                )
+                .subcommand(
+                    Command::new("playtime")
+                        .long_flag("playtime")
+                        .about("Get a user's total playtime in an application, in seconds")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("application-id")
+                                .long("application-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("revenue")
+                        .long_flag("revenue")
+                        .arg(
+                            Arg::new("application-id")
+                                .long("application-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("start")
+                                .long("start")
+                                .value_parser(value_parser!(String))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("end")
+                                .long("end")
+                                .value_parser(value_parser!(String))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("by-developer")
+                        .long_flag("by-developer")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("platforms")
+                        .long_flag("platforms")
+                        .about("List the platforms an application has versions for")
+                        .arg(
+                            Arg::new("application-id")
+                                .long("application-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
                 .subcommand(
                     Command::new("version")
                         .long_flag("version")
@@ -1379,6 +2818,28 @@ fn main() {
                                         .required(true)
                                 )
                         )
+                        .subcommand(
+                            Command::new("download")
+                                .long_flag("download")
+                                .arg(
+                                    Arg::new("version-id")
+                                        .long("version-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("dest")
+                                        .long("dest")
+                                        .value_parser(value_parser!(String))
+                                        .default_value(".")
+                                )
+                                .arg(
+                                    Arg::new("keep-existing")
+                                        .long("keep-existing")
+                                        .action(ArgAction::SetTrue)
+                                        .help("Don't overwrite an existing file; add a suffix to the new file's name instead.")
+                                )
+                        )
                         .subcommand(
                             Command::new("get-fine-tuned")
                                 .long_flag("get-fine-tuned")
@@ -1400,6 +2861,16 @@ fn main() {
                                         .value_parser(value_parser!(String))
                                         .required(true)
                                 )
+                                .arg(
+                                    Arg::new("architecture")
+                                        .long("architecture")
+                                        .value_parser(value_parser!(String))
+                                )
+                                .arg(
+                                    Arg::new("branch")
+                                        .long("branch")
+                                        .value_parser(value_parser!(String))
+                                )
                         )
                         .subcommand(
                             Command::new("get-list")
@@ -1427,6 +2898,55 @@ fn main() {
                                         .required(true)
                                 )
                         )
+                        .subcommand(
+                            Command::new("select-branch")
+                                .long_flag("select-branch")
+                                .about("Select the release branch you get for an application.")
+                                .arg(
+                                    Arg::new("application-id")
+                                        .long("application-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("branch")
+                                        .long("branch")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                        )
+                        .subcommand(
+                            Command::new("selected-branch")
+                                .long_flag("selected-branch")
+                                .arg(
+                                    Arg::new("application-id")
+                                        .long("application-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                        )
+                        .subcommand(
+                            Command::new("set-branch")
+                                .long_flag("set-branch")
+                                .arg(
+                                    Arg::new("application-id")
+                                        .long("application-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("branch")
+                                        .long("branch")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("version-name")
+                                        .long("version-name")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                        )
                         .subcommand(
                             Command::new("create")
                                 .long_flag("create")
@@ -1455,21 +2975,79 @@ fn main() {
                                         .required(true)
                                 )
                                 .arg(
-                                    Arg::new("filename")
-                                        .long("filename")
+                                    Arg::new("filename")
+                                        .long("filename")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("executable")
+                                        .long("executable")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("file")
+                                        .long("file")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("branch")
+                                        .long("branch")
+                                        .value_parser(value_parser!(String))
+                                )
+                                .arg(
+                                    Arg::new("changelog")
+                                        .long("changelog")
+                                        .value_parser(value_parser!(String))
+                                )
+                        )
+                        .subcommand(
+                            Command::new("changelog")
+                                .long_flag("changelog")
+                                .arg(
+                                    Arg::new("version-id")
+                                        .long("version-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                        )
+                )
+                .subcommand(
+                    Command::new("media")
+                        .long_flag("media")
+                        .subcommand_required(true)
+                        .subcommand(
+                            Command::new("add")
+                                .long_flag("add")
+                                .about("Attach a screenshot or trailer to an application's listing.")
+                                .arg(
+                                    Arg::new("application-id")
+                                        .long("application-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("media-type")
+                                        .long("media-type")
                                         .value_parser(value_parser!(String))
-                                        .required(true)
+                                        .default_value("screenshot")
                                 )
                                 .arg(
-                                    Arg::new("executable")
-                                        .long("executable")
+                                    Arg::new("filepath")
+                                        .long("filepath")
                                         .value_parser(value_parser!(String))
                                         .required(true)
                                 )
+                        )
+                        .subcommand(
+                            Command::new("get")
+                                .long_flag("get")
                                 .arg(
-                                    Arg::new("file")
-                                        .long("file")
-                                        .value_parser(value_parser!(String))
+                                    Arg::new("application-id")
+                                        .long("application-id")
+                                        .value_parser(value_parser!(i32))
                                         .required(true)
                                 )
                         )
@@ -1532,6 +3110,20 @@ fn main() {
                             Command::new("get-all")
                                 .long_flag("get-all")
                         )
+                        .subcommand(
+                            Command::new("on-sale")
+                                .long_flag("on-sale")
+                        )
+                        .subcommand(
+                            Command::new("by-developer")
+                                .long_flag("by-developer")
+                                .arg(
+                                    Arg::new("user-id")
+                                        .long("user-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                        )
                         .subcommand(
                             Command::new("delete")
                                 .long_flag("delete")
@@ -1557,6 +3149,17 @@ fn main() {
                                         .required(true)
                                 )
                         )
+                        .subcommand(
+                            Command::new("resolve")
+                                .long_flag("resolve")
+                                .about("Get a key along with the application it unlocks.")
+                                .arg(
+                                    Arg::new("key")
+                                        .long("key")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                        )
                         .subcommand(
                             Command::new("get-list-for")
                                 .long_flag("get-list-for")
@@ -1567,6 +3170,43 @@ fn main() {
                                         .required(true)
                                 )
                         )
+                        .subcommand(
+                            Command::new("revoke")
+                                .long_flag("revoke")
+                                .arg(
+                                    Arg::new("key")
+                                        .long("key")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                        )
+                        .subcommand(
+                            Command::new("extend")
+                                .long_flag("extend")
+                                .about("Extend a time-limited key's validity")
+                                .arg(
+                                    Arg::new("key")
+                                        .long("key")
+                                        .value_parser(value_parser!(String))
+                                        .required(true)
+                                )
+                                .arg(
+                                    Arg::new("days")
+                                        .long("days")
+                                        .value_parser(value_parser!(u32))
+                                        .required(true)
+                                )
+                        )
+                        .subcommand(
+                            Command::new("revoke-all")
+                                .long_flag("revoke-all")
+                                .arg(
+                                    Arg::new("application-id")
+                                        .long("application-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                        )
                 )
         )
         .subcommand(
@@ -1604,6 +3244,15 @@ fn main() {
                                         .required(true)
                                 )
                         )
+                        .subcommand(
+                            Command::new("user-purchases")
+                                .arg(
+                                    Arg::new("user-id")
+                                        .long("user-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                        )
                         .subcommand(
                             Command::new("deposit")
                                 .arg(
@@ -1614,6 +3263,45 @@ fn main() {
                                 )
                         )
                 )
+                .subcommand(
+                    Command::new("receipt")
+                        .long_flag("receipt")
+                        .about("Get a purchase's receipt, optionally saving it as a text file")
+                        .arg(
+                            Arg::new("purchase-id")
+                                .long("purchase-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .value_parser(value_parser!(PathBuf))
+                        )
+                )
+                .subcommand(
+                    Command::new("transfer")
+                        .long_flag("transfer")
+                        .about("Gift part of your balance to another user.")
+                        .arg(
+                            Arg::new("recipient-user-id")
+                                .long("recipient-user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("amount")
+                                .long("amount")
+                                .value_parser(value_parser!(f32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("idempotency-key")
+                                .long("idempotency-key")
+                                .value_parser(value_parser!(String))
+                                .help("A unique key for this transfer; retrying with the same key won't transfer the funds again.")
+                        )
+                )
                 .subcommand(
                     Command::new("buy")
                         .long_flag("buy")
@@ -1628,6 +3316,16 @@ fn main() {
                                         .required(true)
                                 )
                         )
+                        .subcommand(
+                            Command::new("preorder")
+                                .long_flag("preorder")
+                                .arg(
+                                    Arg::new("application-id")
+                                        .long("application-id")
+                                        .value_parser(value_parser!(i32))
+                                        .required(true)
+                                )
+                        )
                         .subcommand(
                             Command::new("iap")
                                 .long_flag("iap")
@@ -1701,6 +3399,18 @@ fn main() {
                                 .value_parser(value_parser!(i32))
                                 .required(true)
                         )
+                        .arg(
+                            Arg::new("online-first")
+                                .long("online-first")
+                                .action(ArgAction::SetTrue)
+                                .help("Include each friend's profile, sorted by online status.")
+                        )
+                        .arg(
+                            Arg::new("online-only")
+                                .long("online-only")
+                                .action(ArgAction::SetTrue)
+                                .help("Like --online-first, but only list online friends.")
+                        )
                 )
                 .subcommand(
                     Command::new("remove")
@@ -1759,6 +3469,26 @@ fn main() {
                                 .required(true)
                         )
                 )
+                .subcommand(
+                    Command::new("accept")
+                        .long_flag("accept")
+                        .arg(
+                            Arg::new("invite-id")
+                                .long("invite-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("decline")
+                        .long_flag("decline")
+                        .arg(
+                            Arg::new("invite-id")
+                                .long("invite-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
                 .subcommand(
                     Command::new("delete")
                         .long_flag("delete")
@@ -1908,7 +3638,24 @@ fn main() {
                             Arg::new("data")
                                 .long("data")
                                 .value_parser(value_parser!(String))
-                                .required(true)
+                                .required_unless_present("file")
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .help("Upload the data from a file (streamed) instead of --data")
+                                .value_parser(value_parser!(PathBuf))
+                                .conflicts_with("data")
+                        )
+                        .arg(
+                            Arg::new("slot")
+                                .long("slot")
+                                .value_parser(value_parser!(String))
+                        )
+                        .arg(
+                            Arg::new("base-date")
+                                .long("base-date")
+                                .value_parser(value_parser!(String))
                         )
                 )
                 .subcommand(
@@ -1926,6 +3673,11 @@ fn main() {
                                 .value_parser(value_parser!(i32))
                                 .required(true)
                         )
+                        .arg(
+                            Arg::new("slot")
+                                .long("slot")
+                                .value_parser(value_parser!(String))
+                        )
                 )
                 .subcommand(
                     Command::new("delete")
@@ -1942,6 +3694,11 @@ fn main() {
                                 .value_parser(value_parser!(i32))
                                 .required(true)
                         )
+                        .arg(
+                            Arg::new("slot")
+                                .long("slot")
+                                .value_parser(value_parser!(String))
+                        )
                 )
                 .subcommand(
                     Command::new("delete-for")
@@ -1953,23 +3710,134 @@ fn main() {
                                 .required(true)
                         )
                 )
+                .subcommand(
+                    Command::new("list-slots")
+                        .long_flag("list-slots")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("application-id")
+                                .long("application-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                )
+                .subcommand(
+                    Command::new("rename-slot")
+                        .long_flag("rename-slot")
+                        .arg(
+                            Arg::new("user-id")
+                                .long("user-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("application-id")
+                                .long("application-id")
+                                .value_parser(value_parser!(i32))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("slot")
+                                .long("slot")
+                                .value_parser(value_parser!(String))
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("new-slot")
+                                .long("new-slot")
+                                .value_parser(value_parser!(String))
+                                .required(true)
+                        )
+                )
         );
     
-    let matches: ArgMatches = command.get_matches();
+    let matches: ArgMatches = command.clone().get_matches();
+    
+    // Shell completions are generated from the command tree itself (no request is made).
+    if let Some(("completions", completions_matches)) = matches.subcommand() {
+        let shell: Shell = completions_matches.get_one::<Shell>("shell").unwrap().to_owned();
+        
+        generate(shell, &mut command, env!("CARGO_BIN_NAME"), &mut io::stdout());
+        
+        return;
+    }
+    
+    if matches.get_flag("no-color") {
+        disable_color();
+    }
     
     let mut api_service: ApiService = ApiService::new("http://192.168.1.16/".to_string());
     
     if let Some(session_id) = matches.get_one::<String>("session-id") { 
-        api_service = api_service.with_authentication(session_id.to_owned());
+        api_service = match api_service.try_with_authentication(session_id.to_owned()) {
+            Ok(api_service) => api_service,
+            Err(err) => {
+                print_error(err);
+                
+                exit(EXIT_BAD_REQUEST);
+            }
+        };
+    }
+    
+    if let Some(seconds) = matches.get_one::<u64>("timeout") {
+        let timeout: Option<Duration> = match seconds {
+            0 => None,
+            seconds => Some(Duration::from_secs(*seconds))
+        };
+        
+        api_service = match api_service.with_timeout(timeout) {
+            Ok(api_service) => api_service,
+            Err(err) => {
+                print_error(err);
+                
+                exit(EXIT_BAD_REQUEST);
+            }
+        };
+    }
+    
+    if matches.get_flag("verbose") {
+        api_service = api_service.with_observer(|method, url, duration, outcome| {
+            let status: String = match outcome {
+                Ok(status_code) => status_code.to_string(),
+                Err(err) => err.to_string()
+            };
+            
+            eprintln!("{} {} -> {} ({:.3}s)", method, url, status, duration.as_secs_f64());
+        });
     }
     
     match matches.subcommand() {
+        Some(("daemon", daemon_matches)) => {
+            match daemon_matches.subcommand() {
+                Some(("status", matches)) => {
+                    handle(|| DaemonStatus::handle_command(api_service, matches));
+                },
+                Some(("open", matches)) => {
+                    handle(|| OpenDeepLink::handle_command(api_service, matches));
+                },
+                _ => unhandled_subcommand()
+            }
+        },
         Some(("server", server_matches)) => {
             match server_matches.subcommand() {
                 Some(("ping", matches)) => {
                     handle(|| Ping::handle_command(api_service, &matches));
                 },
-                _ => {}
+                Some(("version", matches)) => {
+                    handle(|| GetApiVersion::handle_command(api_service, matches));
+                },
+                Some(("limits", matches)) => {
+                    handle(|| GetServerLimits::handle_command(api_service, matches));
+                },
+                Some(("diagnose", matches)) => {
+                    handle(|| Diagnose::handle_command(api_service, matches));
+                },
+                _ => unhandled_subcommand()
             }
         },
         Some(("account", account_matches)) => {
@@ -1980,7 +3848,27 @@ fn main() {
                 Some(("register", register_matches)) => {
                     handle(|| Register::handle_command(api_service, &register_matches));
                 },
-                _ => {}
+                Some(("request-developer", matches)) => {
+                    handle(|| RequestDeveloperStatus::handle_command(api_service, matches));
+                },
+                Some(("check-availability", matches)) => {
+                    handle(|| CheckAvailability::handle_command(api_service, matches));
+                },
+                Some(("export", matches)) => {
+                    handle(|| ExportAccountData::handle_command(api_service, matches));
+                },
+                Some(("change-email", change_email_matches)) => {
+                    match change_email_matches.subcommand() {
+                        Some(("request", matches)) => {
+                            handle(|| RequestEmailChange::handle_command(api_service, matches));
+                        },
+                        Some(("confirm", matches)) => {
+                            handle(|| ConfirmEmailChange::handle_command(api_service, matches));
+                        },
+                        _ => unhandled_subcommand()
+                    }
+                },
+                _ => unhandled_subcommand()
             }
         },
         Some(("session", session_matches)) => {
@@ -1991,10 +3879,13 @@ fn main() {
                 Some(("delete", session_matches)) => {
                     handle(|| DeleteSession::handle_command(api_service, session_matches));
                 },
+                Some(("delete-others", session_matches)) => {
+                    handle(|| DeleteOtherSessions::handle_command(api_service, session_matches));
+                },
                 Some(("get", session_matches)) => {
                     handle(|| GetSession::handle_command(api_service, session_matches))
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("email", email_matches)) => {
@@ -2009,10 +3900,10 @@ fn main() {
                             handle(|| CheckEmailVerification::handle_command(
                                 api_service, verification_matches));
                         },
-                        _ => {}
+                        _ => unhandled_subcommand()
                     }
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("user", user_matches)) => {
@@ -2020,6 +3911,27 @@ fn main() {
                 Some(("get", get_matches)) => {
                     handle(|| GetUser::handle_command(api_service, get_matches));
                 },
+                Some(("profile", matches)) => {
+                    handle(|| GetPublicProfile::handle_command(api_service, matches));
+                },
+                Some(("profile-photo", matches)) => {
+                    handle(|| GetProfilePhoto::handle_command(api_service, matches));
+                },
+                Some(("dashboard", matches)) => {
+                    handle(|| GetDashboard::handle_command(api_service, matches));
+                },
+                Some(("me", matches)) => {
+                    handle(|| GetMe::handle_command(api_service, matches));
+                },
+                Some(("follow", matches)) => {
+                    handle(|| FollowDeveloper::handle_command(api_service, matches));
+                },
+                Some(("unfollow", matches)) => {
+                    handle(|| UnfollowDeveloper::handle_command(api_service, matches));
+                },
+                Some(("following", matches)) => {
+                    handle(|| GetFollowedDevelopers::handle_command(api_service, matches));
+                },
                 Some(("properties", properties_matches)) => {
                     match properties_matches.subcommand() {
                         Some(("get", get_matches)) => {
@@ -2028,20 +3940,30 @@ fn main() {
                                     handle(|| GetIapRecords::handle_command(api_service,
                                                                             matches));
                                 },
+                                Some(("unacknowledged-iap-count", matches)) => {
+                                    handle(|| CountUnacknowledgedIapRecords::handle_command(
+                                        api_service, matches));
+                                },
+                                Some(("balance", matches)) => {
+                                    handle(|| GetBalance::handle_command(api_service, matches));
+                                },
                                 Some(("sessions", matches)) => {
                                     handle(|| GetUserSessions::handle_command(api_service, 
                                                                               matches));
                                 },
-                                _ => {}
+                                _ => unhandled_subcommand()
                             }
                         },
                         Some(("update-profile-photo", matches)) => {
                             handle(|| UpdateProfilePhoto::handle_command(api_service, matches));
                         },
-                        _ => {}
+                        Some(("set-status", matches)) => {
+                            handle(|| SetStatusMessage::handle_command(api_service, matches));
+                        },
+                        _ => unhandled_subcommand()
                     }
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("application", application_matches)) => {
@@ -2049,9 +3971,42 @@ fn main() {
                 Some(("create", create_matches)) => {
                     handle(|| CreateApplication::handle_command(api_service, create_matches));
                 },
+                Some(("import", matches)) => {
+                    handle(|| ImportApplications::handle_command(api_service, matches));
+                },
+                Some(("export", matches)) => {
+                    handle(|| ExportCatalog::handle_command(api_service, matches));
+                },
                 Some(("get", get_matches)) => {
                     handle(|| GetApplication::handle_command(api_service, get_matches));
                 },
+                Some(("get-many", matches)) => {
+                    handle(|| GetApplications::handle_command(api_service, matches));
+                },
+                Some(("genres", matches)) => {
+                    handle(|| GetAllGenres::handle_command(api_service, matches));
+                },
+                Some(("tags", matches)) => {
+                    handle(|| GetAllTags::handle_command(api_service, matches));
+                },
+                Some(("trending", matches)) => {
+                    handle(|| GetTrendingApplications::handle_command(api_service, matches));
+                },
+                Some(("new-releases", matches)) => {
+                    handle(|| GetNewReleases::handle_command(api_service, matches));
+                },
+                Some(("revenue", matches)) => {
+                    handle(|| GetApplicationRevenue::handle_command(api_service, matches));
+                },
+                Some(("playtime", matches)) => {
+                    handle(|| GetTotalPlaytime::handle_command(api_service, matches));
+                },
+                Some(("by-developer", matches)) => {
+                    handle(|| GetDeveloperApplications::handle_command(api_service, matches));
+                },
+                Some(("platforms", matches)) => {
+                    handle(|| GetAvailablePlatforms::handle_command(api_service, matches));
+                },
                 Some(("version", version_matches)) => {
                     match version_matches.subcommand() { 
                         Some(("get-for", get_matches)) => {
@@ -2061,6 +4016,13 @@ fn main() {
                         Some(("get", get_matches)) => {
                             handle(|| GetSpecificApplicationVersion::handle_command(api_service,
                                                                                     get_matches));
+                        },
+                        Some(("download", matches)) => {
+                            handle(|| DownloadApplicationVersion::handle_command(api_service,
+                                                                                 matches));
+                        },
+                        Some(("changelog", matches)) => {
+                            handle(|| GetVersionChangelog::handle_command(api_service, matches));
                         }
                         Some(("get-fine-tuned", get_matches)) => {
                             handle(|| GetFineTunedApplicationVersion::handle_command(api_service,
@@ -2074,11 +4036,31 @@ fn main() {
                             handle(|| UpdateApplicationVersion::handle_command(api_service,
                                                                                update_matches));
                         },
+                        Some(("select-branch", matches)) => {
+                            handle(|| SetUserBranch::handle_command(api_service, matches));
+                        },
+                        Some(("selected-branch", matches)) => {
+                            handle(|| GetUserBranch::handle_command(api_service, matches));
+                        },
+                        Some(("set-branch", matches)) => {
+                            handle(|| SetBranchVersion::handle_command(api_service, matches));
+                        },
                         Some(("create", create_matches)) => {
                             handle(|| CreateApplicationVersion::handle_command(api_service, 
                                                                                create_matches));
                         },
-                        _ => {}
+                        _ => unhandled_subcommand()
+                    }
+                },
+                Some(("media", media_matches)) => {
+                    match media_matches.subcommand() {
+                        Some(("add", matches)) => {
+                            handle(|| AddApplicationMedia::handle_command(api_service, matches));
+                        },
+                        Some(("get", matches)) => {
+                            handle(|| GetApplicationMedia::handle_command(api_service, matches));
+                        },
+                        _ => unhandled_subcommand()
                     }
                 },
                 Some(("sale", sale_matches)) => {
@@ -2092,10 +4074,16 @@ fn main() {
                         Some(("get-all", matches)) => {
                             handle(|| GetAllSales::handle_command(api_service, matches));
                         },
+                        Some(("on-sale", matches)) => {
+                            handle(|| GetApplicationsOnSale::handle_command(api_service, matches));
+                        },
+                        Some(("by-developer", matches)) => {
+                            handle(|| GetDeveloperSales::handle_command(api_service, matches));
+                        },
                         Some(("delete", matches)) => {
                             handle(|| DeleteSale::handle_command(api_service, matches))
                         }
-                        _ => {}
+                        _ => unhandled_subcommand()
                     }
                 },
                 Some(("key", key_matches)) => {
@@ -2103,13 +4091,25 @@ fn main() {
                         Some(("get", get_matches)) => {
                             handle(|| GetApplicationKey::handle_command(api_service, get_matches));
                         },
+                        Some(("resolve", matches)) => {
+                            handle(|| ResolveApplicationKey::handle_command(api_service, matches));
+                        },
                         Some(("get-list-for", matches)) => {
                             handle(|| GetUserApplicationKeys::handle_command(api_service, matches));
                         },
-                        _ => {}
+                        Some(("revoke", matches)) => {
+                            handle(|| RevokeApplicationKey::handle_command(api_service, matches));
+                        },
+                        Some(("revoke-all", matches)) => {
+                            handle(|| RevokeApplicationKeys::handle_command(api_service, matches));
+                        },
+                        Some(("extend", matches)) => {
+                            handle(|| ExtendApplicationKey::handle_command(api_service, matches));
+                        },
+                        _ => unhandled_subcommand()
                     }
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("payment", payment_matches)) => {
@@ -2125,24 +4125,36 @@ fn main() {
                         Some(("purchase", matches)) => {
                             handle(|| GetPurchase::handle_command(api_service, matches));
                         },
+                        Some(("user-purchases", matches)) => {
+                            handle(|| GetUserPurchases::handle_command(api_service, matches));
+                        },
                         Some(("deposit", matches)) => {
                             handle(|| GetDeposit::handle_command(api_service, matches));
                         },
-                        _ => {}
+                        _ => unhandled_subcommand()
                     }
                 },
+                Some(("transfer", matches)) => {
+                    handle(|| TransferBalance::handle_command(api_service, matches));
+                },
+                Some(("receipt", matches)) => {
+                    handle(|| GetPurchaseReceipt::handle_command(api_service, matches));
+                },
                 Some(("buy", buy_matches)) => {
                     match buy_matches.subcommand() {
                         Some(("application", matches)) => {
                             handle(|| PurchaseApplication::handle_command(api_service, matches));
                         },
+                        Some(("preorder", matches)) => {
+                            handle(|| PreorderApplication::handle_command(api_service, matches));
+                        },
                         Some(("iap", matches)) => {
-                            handle(|| PurchaseApplication::handle_command(api_service, matches));
+                            handle(|| PurchaseIap::handle_command(api_service, matches));
                         },
-                        _ => {}
+                        _ => unhandled_subcommand()
                     }
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("friend", friend_matches)) => {
@@ -2165,14 +4177,14 @@ fn main() {
                                     handle(|| GetOutgoingFriendRequests::handle_command(
                                         api_service, outgoing_matching));
                                 },
-                                _ => {}
+                                _ => unhandled_subcommand()
                             }
                         },
                         Some(("accept", accept_matches)) => {
                             handle(|| AcceptFriendRequest::handle_command(api_service, 
                                                                           accept_matches));
                         },
-                        _ => {}
+                        _ => unhandled_subcommand()
                     }
                 },
                 Some(("get-list", matches)) => {
@@ -2181,7 +4193,7 @@ fn main() {
                 Some(("remove", matches)) => {
                     handle(|| RemoveFriend::handle_command(api_service, matches));
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("invite", invite_matches)) => {
@@ -2195,10 +4207,16 @@ fn main() {
                 Some(("get", matches)) => {
                     handle(|| GetInvite::handle_command(api_service, matches));
                 },
+                Some(("accept", matches)) => {
+                    handle(|| AcceptInvite::handle_command(api_service, matches));
+                },
+                Some(("decline", matches)) => {
+                    handle(|| DeclineInvite::handle_command(api_service, matches));
+                },
                 Some(("delete", matches)) => {
                     handle(|| DeleteInvite::handle_command(api_service, matches));
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("photo", photo_matches)) => {
@@ -2209,7 +4227,7 @@ fn main() {
                 Some(("get", get_matches)) => {
                     handle(|| GetPhoto::handle_command(api_service, get_matches));
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("iap", iap_matches)) => {
@@ -2231,10 +4249,10 @@ fn main() {
                         Some(("acknowledge", matches)) => {
                             handle(|| AcknowledgeIapRecord::handle_command(api_service, matches));
                         },
-                        _ => {}
+                        _ => unhandled_subcommand()
                     }
                 },
-                _ => {}
+                _ => unhandled_subcommand()
             }
         },
         Some(("cloud-data", data_matches)) => {
@@ -2251,9 +4269,40 @@ fn main() {
                 Some(("delete-for", matches)) => {
                     handle(|| DeleteApplicationCloudData::handle_command(api_service, matches));
                 }
-                _ => {}
+                Some(("list-slots", matches)) => {
+                    handle(|| ListCloudDataSlots::handle_command(api_service, matches));
+                },
+                Some(("rename-slot", matches)) => {
+                    handle(|| RenameCloudDataSlot::handle_command(api_service, matches));
+                },
+                _ => unhandled_subcommand()
             }
         },
-        _ => {},
+        _ => unhandled_subcommand(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_errors_to_exit_codes() {
+        assert_eq!(exit_code(&APIError::BadRequest(String::new())), EXIT_BAD_REQUEST);
+        assert_eq!(exit_code(&APIError::Unauthorized(String::new())), EXIT_UNAUTHORIZED);
+        assert_eq!(exit_code(&APIError::NotFound(String::new())), EXIT_NOT_FOUND);
+        assert_eq!(exit_code(&APIError::ServerError), EXIT_UNAVAILABLE);
+    }
+
+    #[test]
+    fn maps_unhandled_status_codes_to_exit_codes() {
+        let exit_code_for = |status_code: StatusCode| {
+            exit_code(&APIError::UnhandledStatusCode(status_code))
+        };
+
+        assert_eq!(exit_code_for(StatusCode::NOT_FOUND), EXIT_NOT_FOUND);
+        assert_eq!(exit_code_for(StatusCode::INTERNAL_SERVER_ERROR), EXIT_UNAVAILABLE);
+        assert_eq!(exit_code_for(StatusCode::BAD_GATEWAY), EXIT_UNAVAILABLE);
+        assert_eq!(exit_code_for(StatusCode::IM_A_TEAPOT), EXIT_ERROR);
     }
 }
\ No newline at end of file
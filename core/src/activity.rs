@@ -1,9 +1,32 @@
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{from_value, Value};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Activity {
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub description: String,
     pub details: Value
+}
+
+impl Activity {
+    /// Whether the user is online. Offline users have an empty activity description.
+    pub fn is_online(&self) -> bool {
+        !self.description.is_empty()
+    }
+
+    /// Whether the user is in an application (rather than just online).
+    pub fn is_in_application(&self) -> bool {
+        self.is_online() && self.application_id > 0
+    }
+
+    /// Deserialize the activity's details into an application-defined type.
+    ///
+    /// Applications are free to choose the shape of their details, but a flat object of
+    /// rich-presence fields is recommended, e.g. `{"state": "In a match", "party_size": 3,
+    /// "started": "2024-09-01T12:00:00Z"}`, so that other clients can display it generically.
+    pub fn details_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        from_value(self.details.clone())
+    }
 }
\ No newline at end of file
@@ -1,21 +1,61 @@
 use std::error::Error;
 use std::{fmt, io};
 use std::fmt::{Formatter};
-use reqwest::StatusCode;
+use std::string::FromUtf8Error;
+use std::time::Duration;
+// Re-exported, so callers can inspect `APIError::UnhandledStatusCode` without depending on reqwest.
+pub use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use crate::cloud_data::CloudData;
+use crate::server_error_code::ServerErrorCode;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String
+}
 
 #[derive(Debug)]
 pub enum APIError {
     IOError(io::Error),
     ReqwestError(reqwest::Error),
     JSONError(serde_json::Error),
+    URLError(url::ParseError),
+    InvalidUtf8(FromUtf8Error),
+    #[cfg(feature = "websocket")]
+    WebSocketError(Box<tungstenite::Error>),
     Forbidden(String),
     Unauthorized(String),
     NotFound(String),
     BadRequest(String),
+    Conflict(CloudData),
+    Validation(Vec<FieldError>),
+    RateLimited { retry_after: Option<Duration> },
+    Maintenance { retry_after: Option<u64>, message: String },
+    InsufficientBalance { balance: f32, price: f32 },
+    /// An application with the package name (the contained value) already exists.
+    DuplicatePackageName(String),
+    ResponseTooLarge,
+    IncompatibleVersion { client: String, server: String },
     ServerError,
     UnhandledStatusCode(StatusCode)
 }
 
+impl APIError {
+    /// Get the server's error identifier (e.g. `email_taken`), if the error carries one, so
+    /// specific failures can be matched on rather than string-compared.
+    pub fn code(&self) -> Option<ServerErrorCode> {
+        match self {
+            APIError::BadRequest(ref body) |
+            APIError::Unauthorized(ref body) |
+            APIError::Forbidden(ref body) => ServerErrorCode::parse(body),
+            APIError::InsufficientBalance { .. } => Some(ServerErrorCode::InsufficientBalance),
+            APIError::DuplicatePackageName(_) => Some(ServerErrorCode::PackageNameTaken),
+            _ => None
+        }
+    }
+}
+
 // Implement Display for APIError.
 impl fmt::Display for APIError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -23,11 +63,42 @@ impl fmt::Display for APIError {
             APIError::IOError(ref error) => write!(f, "IO error: {}", error),
             APIError::ReqwestError(ref error) => write!(f, "Reqwest error: {}", error),
             APIError::JSONError(ref error) => write!(f, "JSON error: {}", error),
+            APIError::URLError(ref error) => write!(f, "URL error: {}", error),
+            APIError::InvalidUtf8(ref error) => write!(f, "Invalid UTF-8 in response: {}", error),
+            #[cfg(feature = "websocket")]
+            APIError::WebSocketError(ref error) => write!(f, "WebSocket error: {}", error),
             APIError::Forbidden(ref message) => write!(f, "Forbidden! {}", message),
             APIError::Unauthorized(ref message) => write!(f, "Unauthorized! {}", message),
             APIError::NotFound(ref message) => write!(f, "Not found! {}", message),
             APIError::BadRequest(ref message) =>
                 write!(f, "Bad request! {}", message),
+            APIError::Conflict(ref cloud_data) =>
+                write!(f, "Conflict! The server has a newer copy from {}", cloud_data.date),
+            APIError::Validation(ref errors) => {
+                let messages: Vec<String> = errors.iter()
+                    .map(|error| format!("{}: {}", error.field, error.message))
+                    .collect();
+
+                write!(f, "Validation failed! {}", messages.join(", "))
+            },
+            APIError::RateLimited { retry_after: Some(ref retry_after) } =>
+                write!(f, "Rate limited! Retry after {} seconds.", retry_after.as_secs()),
+            APIError::RateLimited { retry_after: None } => write!(f, "Rate limited!"),
+            APIError::Maintenance { retry_after: Some(retry_after), ref message } =>
+                write!(f, "Down for maintenance! {} (retry after {} seconds)", message,
+                       retry_after),
+            APIError::Maintenance { retry_after: None, ref message } =>
+                write!(f, "Down for maintenance! {}", message),
+            APIError::InsufficientBalance { balance, price } =>
+                write!(f, "Insufficient balance! The price is {:.2}, but the balance is {:.2}.",
+                       price, balance),
+            APIError::DuplicatePackageName(ref package_name) =>
+                write!(f, "The package name {} is already taken; choose another one.",
+                       package_name),
+            APIError::IncompatibleVersion { ref client, ref server } =>
+                write!(f, "Incompatible server API version {} (this client requires {}); please \
+                           update the client.", server, client),
+            APIError::ResponseTooLarge => write!(f, "Response too large!"),
             APIError::ServerError => write!(f, "Server error!{}", ""),
             APIError::UnhandledStatusCode(ref status_code) =>
                 write!(f, "Unhandled status code: {}", status_code.as_str())
@@ -40,6 +111,7 @@ impl Error for APIError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             APIError::IOError(ref err) => Some(err),
+            APIError::InvalidUtf8(ref err) => Some(err),
             _ => None,
         }
     }
@@ -64,4 +136,26 @@ impl From<serde_json::Error> for APIError {
     fn from(value: serde_json::Error) -> Self {
         APIError::JSONError(value)
     }
+}
+
+// Allow automatic conversion from url::ParseError to APIError.
+impl From<url::ParseError> for APIError {
+    fn from(value: url::ParseError) -> Self {
+        APIError::URLError(value)
+    }
+}
+
+// Allow automatic conversion from FromUtf8Error to APIError.
+impl From<FromUtf8Error> for APIError {
+    fn from(value: FromUtf8Error) -> Self {
+        APIError::InvalidUtf8(value)
+    }
+}
+
+// Allow automatic conversion from tungstenite::Error to APIError.
+#[cfg(feature = "websocket")]
+impl From<tungstenite::Error> for APIError {
+    fn from(value: tungstenite::Error) -> Self {
+        APIError::WebSocketError(Box::new(value))
+    }
 }
\ No newline at end of file
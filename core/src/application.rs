@@ -4,15 +4,24 @@ use serde::{Deserialize, Serialize};
 pub struct Application {
     pub id: i32,
     pub name: String,
+    #[serde(alias = "packageName")]
     pub package_name: String,
     pub r#type: String,
     pub description: String,
+    #[serde(alias = "releaseDate")]
     pub release_date: String,
+    #[serde(alias = "earlyAccess")]
     pub early_access: bool,
-    pub latest_version: String,
+    #[serde(default)]
+    pub preorder: bool,
+    // Absent until the first version has been published.
+    #[serde(alias = "latestVersion")]
+    pub latest_version: Option<String>,
+    #[serde(alias = "supportedPlatforms")]
     pub supported_platforms: Vec<String>,
     pub genres: Vec<String>,
     pub tags: Vec<String>,
+    #[serde(alias = "basePrice", with = "crate::price")]
     pub base_price: f32,
     pub owners: Vec<i32>
 }
\ No newline at end of file
@@ -3,9 +3,14 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ApplicationKey {
     pub id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub key: String,
     pub r#type: String,
     pub redeemed: bool,
-    pub user_id: i32
+    #[serde(alias = "userId")]
+    pub user_id: i32,
+    // When a time-limited key (e.g. a trial or rental) expires (absent for permanent keys).
+    #[serde(alias = "expiresAt")]
+    pub expires_at: Option<String>
 }
\ No newline at end of file
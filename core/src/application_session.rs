@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ApplicationSession {
     pub id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub date: String,
     pub length: i32
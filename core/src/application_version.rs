@@ -3,10 +3,61 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ApplicationVersion {
     pub id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub r#name: String,
     pub platform: String,
+    pub architecture: Option<String>,
+    pub branch: Option<String>,
+    #[serde(alias = "releaseDate")]
     pub release_date: String,
     pub filename: String,
-    pub executable: String
+    pub executable: String,
+    // The version's patch notes (absent for versions uploaded without any).
+    pub changelog: Option<String>
+}
+
+/// A version to upload with `ApiService::create_application_version`. The branch and changelog
+/// are optional, and set with `branch` and `changelog`.
+#[derive(Debug, Clone)]
+pub struct NewApplicationVersion {
+    pub application_id: i32,
+    pub name: String,
+    pub platform: String,
+    pub release_date: String,
+    pub filename: String,
+    pub executable: String,
+    /// The path of the file to upload.
+    pub filepath: String,
+    /// The release branch the version belongs to (defaults to `DEFAULT_BRANCH`).
+    pub branch: Option<String>,
+    /// The version's patch notes, if any.
+    pub changelog: Option<String>
+}
+
+impl NewApplicationVersion {
+    pub fn new(application_id: i32, name: String, platform: String, release_date: String,
+               filename: String, executable: String, filepath: String) -> Self {
+        Self {
+            application_id,
+            name,
+            platform,
+            release_date,
+            filename,
+            executable,
+            filepath,
+            branch: None,
+            changelog: None
+        }
+    }
+
+    pub fn branch(mut self, branch: String) -> Self {
+        self.branch = Some(branch);
+        self
+    }
+
+    pub fn changelog(mut self, changelog: String) -> Self {
+        self.changelog = Some(changelog);
+        self
+    }
 }
\ No newline at end of file
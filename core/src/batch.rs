@@ -0,0 +1,65 @@
+use serde::Serialize;
+use serde_json::{to_value, Value};
+use crate::api_error::APIError;
+use crate::{ApiResult, ApiService};
+
+type Operation<'a> = Box<dyn FnOnce(&ApiService) -> ApiResult<Value> + 'a>;
+
+/// The outcome of a batch: the results of the operations that succeeded (in order), and the error
+/// that stopped the batch, if any.
+#[derive(Debug)]
+pub struct BatchResult {
+    pub results: Vec<Value>,
+    pub error: Option<APIError>
+}
+
+impl BatchResult {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// A queue of operations to run one after another against the same `ApiService`, stopping at the
+/// first one that fails.
+pub struct Batch<'a> {
+    api_service: &'a ApiService,
+    operations: Vec<Operation<'a>>
+}
+
+impl<'a> Batch<'a> {
+    pub fn new(api_service: &'a ApiService) -> Self {
+        Self {
+            api_service,
+            operations: Vec::new()
+        }
+    }
+
+    /// Queue an operation. Its result is converted to JSON when the batch is executed.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation, e.g. `|api| api.login(username, password)`
+    pub fn then<T, F>(mut self, operation: F) -> Self
+    where
+        F: FnOnce(&ApiService) -> ApiResult<T> + 'a,
+        T: Serialize
+    {
+        self.operations.push(Box::new(move |api_service| {
+            Ok(to_value(operation(api_service)?)?)
+        }));
+        self
+    }
+
+    /// Run the queued operations in order, stopping at the first error.
+    pub fn execute(self) -> BatchResult {
+        let mut results: Vec<Value> = Vec::with_capacity(self.operations.len());
+
+        for operation in self.operations {
+            match operation(self.api_service) {
+                Ok(result) => results.push(result),
+                Err(err) => return BatchResult { results, error: Some(err) }
+            }
+        }
+
+        BatchResult { results, error: None }
+    }
+}
\ No newline at end of file
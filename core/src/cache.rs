@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, to_string};
+
+/// A value returned by an offline-capable request, flagged with whether it came from the cache
+/// because the server was unreachable.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CachedResponse<T> {
+    pub value: T,
+    pub offline: bool
+}
+
+/// A cached response, with when it was stored (in milliseconds since the Unix epoch).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CacheEntry {
+    stored_at: u64,
+    data: String
+}
+
+/// The cache's contents (as persisted to disk).
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct CacheContents {
+    /// The user the cached responses belong to.
+    user_id: Option<i32>,
+    entries: HashMap<String, CacheEntry>
+}
+
+/// A cache of successful responses, used to serve data while offline. Responses are stored per
+/// user, and the cache is optionally persisted to a file, so it survives restarts.
+pub struct ResponseCache {
+    ttl: Duration,
+    path: Option<PathBuf>,
+    contents: Mutex<CacheContents>
+}
+
+impl ResponseCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            path: None,
+            contents: Mutex::new(CacheContents::default())
+        }
+    }
+
+    /// Open a cache persisted to a file, loading its existing contents (a missing or unreadable
+    /// file starts an empty cache).
+    pub fn open(path: PathBuf, ttl: Duration) -> Self {
+        let contents: CacheContents = read_to_string(&path).ok()
+            .and_then(|data| from_str(&data).ok())
+            .unwrap_or_default();
+
+        Self {
+            ttl,
+            path: Some(path),
+            contents: Mutex::new(contents)
+        }
+    }
+
+    /// Get an empty cache with the same time-to-live and file as this one.
+    pub fn emptied(&self) -> Self {
+        let cache: Self = Self {
+            ttl: self.ttl,
+            path: self.path.clone(),
+            contents: Mutex::new(CacheContents::default())
+        };
+
+        cache.save(&cache.contents.lock().unwrap());
+
+        cache
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Get the user the cached responses belong to.
+    pub fn user_id(&self) -> Option<i32> {
+        self.contents.lock().unwrap().user_id
+    }
+
+    /// Set the user the cached responses belong to, clearing the cache if it belonged to another
+    /// user.
+    pub fn set_user_id(&self, user_id: i32) {
+        let mut contents = self.contents.lock().unwrap();
+
+        if contents.user_id == Some(user_id) {
+            return;
+        }
+
+        contents.user_id = Some(user_id);
+        contents.entries.clear();
+        self.save(&contents);
+    }
+
+    /// Store a value in the cache (replacing any existing value for the key).
+    pub fn insert<T: Serialize>(&self, key: String, value: &T) {
+        if let Ok(data) = to_string(value) {
+            let mut contents = self.contents.lock().unwrap();
+            let key: String = Self::user_key(contents.user_id, &key);
+
+            contents.entries.insert(key, CacheEntry { stored_at: now(), data });
+            self.save(&contents);
+        }
+    }
+
+    /// Get a value from the cache, if one exists and it hasn't expired.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let contents = self.contents.lock().unwrap();
+        let entry: &CacheEntry = contents.entries.get(&Self::user_key(contents.user_id, key))?;
+
+        if u128::from(now().saturating_sub(entry.stored_at)) > self.ttl.as_millis() {
+            return None;
+        }
+
+        from_str(&entry.data).ok()
+    }
+
+    /// Remove every cached response, and forget which user they belonged to.
+    pub fn clear(&self) {
+        let mut contents = self.contents.lock().unwrap();
+
+        *contents = CacheContents::default();
+        self.save(&contents);
+    }
+
+    /// Prefix a key with the user the response belongs to.
+    fn user_key(user_id: Option<i32>, key: &str) -> String {
+        match user_id {
+            Some(user_id) => format!("{}:{}", user_id, key),
+            None => format!("anonymous:{}", key)
+        }
+    }
+
+    /// Write the cache to its file (if it has one). The cache is only an optimisation, so
+    /// failing to write it isn't an error.
+    fn save(&self, contents: &CacheContents) {
+        let path: &PathBuf = match &self.path {
+            Some(path) => path,
+            None => return
+        };
+
+        if let Some(parent) = path.parent() {
+            let _ = create_dir_all(parent);
+        }
+
+        if let Ok(data) = to_string(contents) {
+            let _ = write(path, data);
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_millis() as u64)
+}
\ No newline at end of file
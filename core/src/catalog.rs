@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use crate::application::Application;
+
+/// Everything needed to create an application, as stored in a catalog manifest (a JSON array of
+/// definitions, see `ApiService::export_catalog`).
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApplicationDefinition {
+    pub name: String,
+    pub package_name: String,
+    #[serde(rename = "type", alias = "application_type")]
+    pub application_type: String,
+    pub description: String,
+    pub release_date: String,
+    #[serde(default)]
+    pub early_access: bool,
+    pub supported_platforms: Vec<String>,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(with = "crate::price")]
+    pub base_price: f32
+}
+
+impl From<Application> for ApplicationDefinition {
+    fn from(application: Application) -> Self {
+        Self {
+            name: application.name,
+            package_name: application.package_name,
+            application_type: application.r#type,
+            description: application.description,
+            release_date: application.release_date,
+            early_access: application.early_access,
+            supported_platforms: application.supported_platforms,
+            genres: application.genres,
+            tags: application.tags,
+            base_price: application.base_price
+        }
+    }
+}
\ No newline at end of file
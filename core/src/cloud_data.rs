@@ -4,8 +4,11 @@ use serde_json::Value;
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CloudData {
     pub id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub data: Value,
+    pub slot: Option<String>,
     pub date: String
 }
\ No newline at end of file
@@ -0,0 +1,120 @@
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::path::PathBuf;
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_slice, to_vec, Value};
+use crate::ApiResult;
+
+pub const DAEMON_IP: &str = "127.0.0.1";
+pub const DAEMON_PORT: u16 = 57222;
+
+/// How long to wait before retrying the connection (giving a freshly spawned daemon time to start
+/// listening).
+const RETRY_DELAY: Duration = Duration::from_millis(1500);
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DaemonMessage {
+    pub r#type: String,
+    pub data: Value
+}
+
+impl DaemonMessage {
+    pub fn new(r#type: &str, data: Value) -> Self {
+        Self {
+            r#type: r#type.to_string(),
+            data
+        }
+    }
+}
+
+/// The daemon's reply to a message.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DaemonResponse {
+    pub success: bool,
+    #[serde(default)]
+    pub data: Value
+}
+
+impl DaemonResponse {
+    pub fn ok() -> Self {
+        Self { success: true, data: Value::Null }
+    }
+
+    pub fn error(message: &str) -> Self {
+        Self { success: false, data: Value::String(message.to_string()) }
+    }
+}
+
+pub fn get_daemon_address() -> String {
+    format!("{}:{}", DAEMON_IP, DAEMON_PORT)
+}
+
+/// A client for the daemon's local TCP server. Each message is sent on its own connection.
+pub struct DaemonClient {
+    address: String,
+    executable: Option<PathBuf>
+}
+
+impl Default for DaemonClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DaemonClient {
+    pub fn new() -> Self {
+        Self {
+            address: get_daemon_address(),
+            executable: None
+        }
+    }
+
+    /// Use a different daemon address (e.g. an ephemeral port in tests).
+    pub fn with_address(mut self, address: &str) -> Self {
+        self.address = address.to_string();
+        self
+    }
+
+    /// Spawn the daemon from the specified executable if it isn't running when connecting.
+    pub fn with_spawn(mut self, executable: PathBuf) -> Self {
+        self.executable = Some(executable);
+        self
+    }
+
+    /// Connect to the daemon. If it isn't up, the connection is retried once after a short delay
+    /// (spawning the daemon first, if an executable was specified).
+    pub fn connect(&self) -> ApiResult<TcpStream> {
+        if let Ok(stream) = TcpStream::connect(&self.address) {
+            return Ok(stream);
+        }
+
+        if let Some(executable) = &self.executable {
+            Command::new(executable).spawn()?;
+        }
+
+        thread::sleep(RETRY_DELAY);
+
+        Ok(TcpStream::connect(&self.address)?)
+    }
+
+    /// Send a message to the daemon and wait for its response.
+    ///
+    /// # Arguments
+    /// * `message` - The message to send
+    pub fn send(&self, message: &DaemonMessage) -> ApiResult<DaemonResponse> {
+        let mut stream: TcpStream = self.connect()?;
+
+        stream.write_all(&to_vec(message)?)?;
+
+        // Signal that the whole message has been sent.
+        stream.shutdown(Shutdown::Write)?;
+
+        let mut buffer: Vec<u8> = Vec::new();
+        stream.read_to_end(&mut buffer)?;
+
+        Ok(from_slice(&buffer)?)
+    }
+}
\ No newline at end of file
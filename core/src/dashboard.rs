@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use crate::application::Application;
+use crate::friend_request::FriendRequest;
+use crate::invite::Invite;
+use crate::sale::Sale;
+use crate::user::User;
+
+/// Everything a launcher's home screen needs, fetched in a single request.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Dashboard {
+    pub user: User,
+    pub library: Vec<Application>,
+    pub invites: Vec<Invite>,
+    #[serde(alias = "friendRequests")]
+    pub friend_requests: Vec<FriendRequest>,
+    #[serde(alias = "activeSales")]
+    pub active_sales: Vec<Sale>
+}
\ No newline at end of file
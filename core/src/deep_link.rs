@@ -0,0 +1,77 @@
+use std::fmt;
+use std::fmt::Formatter;
+
+/// The URL scheme registered by the installer.
+pub const DEEP_LINK_SCHEME: &str = "frogworks";
+
+/// A `frogworks://` link, as generated for (e.g.) a web "Install" button and handled by the daemon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeepLink {
+    Install(i32),
+    Launch(i32),
+    Store(i32)
+}
+
+impl DeepLink {
+    fn action(&self) -> &'static str {
+        match self {
+            DeepLink::Install(_) => "install",
+            DeepLink::Launch(_) => "launch",
+            DeepLink::Store(_) => "store"
+        }
+    }
+
+    fn application_id(&self) -> i32 {
+        match *self {
+            DeepLink::Install(application_id) => application_id,
+            DeepLink::Launch(application_id) => application_id,
+            DeepLink::Store(application_id) => application_id
+        }
+    }
+
+    /// Parse a link (e.g. `frogworks://install/42`), returning `None` if it isn't a valid
+    /// `frogworks://` link or the application id isn't positive. A trailing slash is allowed.
+    pub fn parse(link: &str) -> Option<Self> {
+        let path: &str = link.strip_prefix(DEEP_LINK_SCHEME)?.strip_prefix("://")?;
+        let (action, application_id) = path.strip_suffix('/').unwrap_or(path).split_once('/')?;
+
+        // Only plain digits are accepted (`i32::parse` also allows a sign).
+        if !application_id.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let application_id: i32 = application_id.parse().ok()?;
+
+        if application_id <= 0 {
+            return None;
+        }
+
+        match action {
+            "install" => Some(DeepLink::Install(application_id)),
+            "launch" => Some(DeepLink::Launch(application_id)),
+            "store" => Some(DeepLink::Store(application_id)),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for DeepLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}/{}", DEEP_LINK_SCHEME, self.action(), self.application_id())
+    }
+}
+
+/// Get the link that installs an application.
+pub fn deep_link_install(application_id: i32) -> String {
+    DeepLink::Install(application_id).to_string()
+}
+
+/// Get the link that launches an (installed) application.
+pub fn deep_link_launch(application_id: i32) -> String {
+    DeepLink::Launch(application_id).to_string()
+}
+
+/// Get the link that opens an application's store page.
+pub fn deep_link_store(application_id: i32) -> String {
+    DeepLink::Store(application_id).to_string()
+}
\ No newline at end of file
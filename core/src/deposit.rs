@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Deposit {
     pub id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
+    #[serde(with = "crate::price")]
     pub amount: f32,
     pub source: String,
     pub date: String
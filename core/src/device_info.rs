@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+use std::ffi::OsString;
+use gethostname::gethostname;
+use serde::{Deserialize, Serialize};
+use crate::platform::Platform;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeviceInfo {
+    pub hostname: String,
+    pub mac_address: Option<String>,
+    pub platform: Platform
+}
+
+impl DeviceInfo {
+    /// Collect the device details (hostname, MAC address, platform) of the current machine.
+    pub fn collect() -> Self {
+        let hostname: OsString = gethostname();
+        let hostname_cow: Cow<str> = hostname.to_string_lossy();
+
+        Self {
+            hostname: hostname_cow.into_owned(),
+            mac_address: get_mac_address(),
+            platform: Platform::current()
+        }
+    }
+}
+
+fn get_mac_address() -> Option<String> {
+    match mac_address::get_mac_address() {
+        Ok(Some(mac_address)) => Some(format!("{}", mac_address)),
+        Ok(None) | Err(_) => None
+    }
+}
\ No newline at end of file
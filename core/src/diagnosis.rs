@@ -0,0 +1,61 @@
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
+
+/// A stage of the connectivity self-test (see `ApiService::diagnose`), in the order they're run.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticStage {
+    Url,
+    Dns,
+    Tcp,
+    Ping
+}
+
+/// The outcome of a single stage of the self-test.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StageReport {
+    pub stage: DiagnosticStage,
+    pub success: bool,
+    /// How long the stage took, in milliseconds.
+    pub duration_ms: u64,
+    /// What the stage found (e.g. the resolved addresses), or why it failed.
+    pub detail: String
+}
+
+/// The result of a connectivity self-test. The self-test stops at the first stage that fails, so
+/// a failed stage is always the last one reported.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Diagnosis {
+    pub stages: Vec<StageReport>
+}
+
+impl Diagnosis {
+    /// Whether every stage passed.
+    pub fn success(&self) -> bool {
+        !self.stages.is_empty() && self.failed_stage().is_none()
+    }
+
+    /// Get the stage that failed, if any.
+    pub fn failed_stage(&self) -> Option<DiagnosticStage> {
+        self.stages.iter()
+            .find(|report| !report.success)
+            .map(|report| report.stage)
+    }
+
+    pub(crate) fn pass(&mut self, stage: DiagnosticStage, start: Instant, detail: String) {
+        self.record(stage, true, start, detail);
+    }
+
+    pub(crate) fn fail(&mut self, stage: DiagnosticStage, start: Instant, detail: String) {
+        self.record(stage, false, start, detail);
+    }
+
+    fn record(&mut self, stage: DiagnosticStage, success: bool, start: Instant, detail: String) {
+        self.stages.push(StageReport {
+            stage,
+            success,
+            duration_ms: start.elapsed().as_millis() as u64,
+            detail
+        });
+    }
+}
\ No newline at end of file
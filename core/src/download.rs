@@ -0,0 +1,104 @@
+use std::fs::{create_dir_all, remove_file, rename, File};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+/// What to do when a downloaded file would replace an existing one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollisionPolicy {
+    /// Replace the existing file.
+    #[default]
+    Overwrite,
+    /// Keep the existing file, and append a suffix to the new one's name (e.g. `game (1).exe`).
+    Rename
+}
+
+/// Resolve where a downloaded file should be written: the folder is created if it doesn't exist
+/// and made absolute, and collisions are handled according to the policy. A filename that isn't a
+/// plain file name (see `is_safe_filename`) fails with `io::ErrorKind::InvalidInput`.
+///
+/// # Arguments
+/// * `folder` - The folder to download to (may be relative to the working directory)
+/// * `filename` - The downloaded file's name
+/// * `policy` - What to do if the file already exists
+pub fn resolve_download_path(folder: &Path, filename: &str,
+                             policy: CollisionPolicy) -> io::Result<PathBuf> {
+    if !is_safe_filename(filename) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Invalid filename: {:?}", filename)
+        ));
+    }
+
+    create_dir_all(folder)?;
+
+    let folder: PathBuf = folder.canonicalize()?;
+    let filepath: PathBuf = folder.join(filename);
+
+    if policy == CollisionPolicy::Overwrite || !filepath.exists() {
+        return Ok(filepath);
+    }
+
+    // Split the name so the suffix goes before the extension.
+    let stem: String = Path::new(filename).file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| filename.to_string());
+    let extension: Option<String> = Path::new(filename).extension()
+        .map(|extension| extension.to_string_lossy().into_owned());
+
+    let mut n: u32 = 1;
+
+    loop {
+        let candidate: String = match &extension {
+            Some(extension) => format!("{} ({}).{}", stem, n, extension),
+            None => format!("{} ({})", stem, n)
+        };
+        let filepath: PathBuf = folder.join(candidate);
+
+        if !filepath.exists() {
+            return Ok(filepath);
+        }
+
+        n += 1;
+    }
+}
+
+/// Check that a file name (e.g. one sent by the server) is a plain name, which can't point outside
+/// the folder it's joined onto: no path separators, no `..`, and no drive prefix.
+pub fn is_safe_filename(filename: &str) -> bool {
+    let mut components = Path::new(filename).components();
+
+    matches!((components.next(), components.next()), (Some(Component::Normal(_)), None))
+        && !filename.contains(['/', '\\', ':'])
+}
+
+/// A file being downloaded, which is removed when dropped unless it has been moved into place, so
+/// failed downloads don't leave partial files behind.
+pub(crate) struct PartialFile {
+    path: PathBuf,
+    persisted: bool
+}
+
+impl PartialFile {
+    /// Create (or truncate) the partial file, returning the guard and the file to write to.
+    pub(crate) fn create(path: PathBuf) -> io::Result<(Self, File)> {
+        let file: File = File::create(&path)?;
+
+        Ok((Self { path, persisted: false }, file))
+    }
+
+    /// Move the finished file into place (the file written to must be closed first).
+    pub(crate) fn persist(mut self, dest: &Path) -> io::Result<()> {
+        rename(&self.path, dest)?;
+        self.persisted = true;
+
+        Ok(())
+    }
+}
+
+impl Drop for PartialFile {
+    fn drop(&mut self) {
+        if !self.persisted {
+            let _ = remove_file(&self.path);
+        }
+    }
+}
\ No newline at end of file
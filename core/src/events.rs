@@ -0,0 +1,64 @@
+use std::io::{BufRead, BufReader};
+use reqwest::blocking::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, Value};
+use crate::ApiResult;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ServerEvent {
+    pub kind: String,
+    pub payload: Value
+}
+
+/// An iterator over the server-sent events of an open event stream.
+pub struct EventStream {
+    reader: BufReader<Response>
+}
+
+impl EventStream {
+    pub fn new(response: Response) -> Self {
+        Self { reader: BufReader::new(response) }
+    }
+}
+
+impl Iterator for EventStream {
+    type Item = ApiResult<ServerEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut kind: String = String::from("message");
+        let mut data: Vec<String> = Vec::new();
+
+        loop {
+            let mut line: String = String::new();
+
+            match self.reader.read_line(&mut line) {
+                // The stream has been closed.
+                Ok(0) => return None,
+                Ok(_) => {},
+                Err(err) => return Some(Err(err.into()))
+            }
+
+            let line: &str = line.trim_end_matches(['\r', '\n']);
+
+            if line.is_empty() {
+                // A blank line dispatches the event (if it had any data).
+                if data.is_empty() {
+                    continue;
+                }
+
+                let payload: ApiResult<Value> = from_str(&data.join("\n"))
+                    .map_err(|err| err.into());
+
+                return Some(payload.map(|payload| ServerEvent { kind, payload }));
+            }
+
+            if let Some(value) = line.strip_prefix("event:") {
+                kind = value.trim().to_string();
+            } else if let Some(value) = line.strip_prefix("data:") {
+                data.push(value.trim().to_string());
+            }
+
+            // Anything else (comments, ids, retry hints) is ignored.
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+/// A field's value: either text, or a list of values (e.g. an application's tags).
+enum FieldValue {
+    Text(String),
+    List(Vec<String>)
+}
+
+impl FieldValue {
+    /// Get the value as form text. Lists are joined with commas, which is how the server reads
+    /// list fields in multipart forms (so their values can't contain commas).
+    fn into_text(self) -> String {
+        match self {
+            FieldValue::Text(text) => text,
+            FieldValue::List(values) => values.join(",")
+        }
+    }
+}
+
+/// A set of text-only request fields, which can be sent either as a multipart form or as a JSON
+/// body.
+#[derive(Default)]
+pub struct FormFields {
+    fields: Vec<(String, FieldValue)>
+}
+
+impl FormFields {
+    pub fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    pub fn text<T, U>(mut self, name: T, value: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>
+    {
+        self.fields.push((name.into(), FieldValue::Text(value.into())));
+        self
+    }
+
+    /// Add a list field, sent as an array in JSON bodies, and comma-separated in multipart forms.
+    pub fn list<T, U>(mut self, name: T, values: &[U]) -> Self
+    where
+        T: Into<String>,
+        U: ToString
+    {
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+
+        self.fields.push((name.into(), FieldValue::List(values)));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Encode the fields as a multipart form, returning the content type (including the boundary)
+    /// and the body. Unlike a streamed `multipart::Form`, the body is buffered, so the request can
+    /// be cloned and retried.
+    pub fn into_multipart(self) -> (String, Vec<u8>) {
+        let boundary: String = Uuid::new_v4().simple().to_string();
+        let mut body: Vec<u8> = Vec::new();
+
+        for (name, value) in self.fields {
+            body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+            body.extend_from_slice(
+                format!("Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                        escape_name(&name)).as_bytes()
+            );
+            body.extend_from_slice(value.into_text().as_bytes());
+            body.extend_from_slice(b"\r\n");
+        }
+
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+        (format!("multipart/form-data; boundary={}", boundary), body)
+    }
+
+    pub fn into_json(self) -> Value {
+        let map: Map<String, Value> = self.fields.into_iter()
+            .map(|(name, value)| {
+                let value: Value = match value {
+                    FieldValue::Text(text) => Value::String(text),
+                    FieldValue::List(values) => {
+                        Value::Array(values.into_iter().map(Value::String).collect())
+                    }
+                };
+
+                (name, value)
+            })
+            .collect();
+
+        Value::Object(map)
+    }
+}
+
+/// Percent-encode the characters that can't appear in a quoted `Content-Disposition` parameter
+/// (quotes and line breaks), as browsers do, so a field name can't end the header early or inject
+/// another part.
+fn escape_name(name: &str) -> String {
+    name.replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
\ No newline at end of file
@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Friend {
     pub id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
+    #[serde(alias = "otherUserId")]
     pub other_user_id: i32,
     pub date: String
 }
\ No newline at end of file
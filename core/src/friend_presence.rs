@@ -0,0 +1,35 @@
+use std::cmp::Ordering;
+use serde::{Deserialize, Serialize};
+use crate::friend::Friend;
+use crate::public_profile::PublicProfile;
+
+/// A friend together with their public profile (and so their current activity).
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FriendPresence {
+    pub friend: Friend,
+    pub profile: PublicProfile
+}
+
+impl FriendPresence {
+    pub fn is_online(&self) -> bool {
+        self.profile.activity.is_online()
+    }
+
+    /// Order friends online-first: friends in an application, then other online friends, then
+    /// offline friends, each alphabetically by username.
+    pub fn online_first(&self, other: &Self) -> Ordering {
+        let rank = |presence: &Self| {
+            if presence.profile.activity.is_in_application() {
+                0
+            } else if presence.is_online() {
+                1
+            } else {
+                2
+            }
+        };
+
+        rank(self).cmp(&rank(other))
+            .then_with(|| self.profile.username.to_lowercase()
+                .cmp(&other.profile.username.to_lowercase()))
+    }
+}
\ No newline at end of file
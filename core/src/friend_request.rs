@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FriendRequest {
     pub id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
+    #[serde(alias = "fromUserId")]
     pub from_user_id: i32,
     pub date: String
 }
\ No newline at end of file
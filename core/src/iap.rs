@@ -4,9 +4,11 @@ use serde_json::Value;
 #[derive(Serialize, Deserialize, Debug)]
 pub struct IAP {
     pub id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub title: String,
     pub description: String,
+    #[serde(with = "crate::price")]
     pub price: f32,
     pub data: Value
 }
\ No newline at end of file
@@ -3,8 +3,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct IAPRecord {
     pub id: i32,
+    #[serde(alias = "iapId")]
     pub iap_id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub date: String,
     pub acknowledged: bool
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// A progress event emitted by `install_application_version`, for showing a staged progress UI.
+/// Versions are distributed as a single file, so the stages are download, verify, and done (there
+/// is no extraction or local registration step).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum InstallEvent {
+    /// Bytes downloaded so far (`total` is `None` if the server didn't send a content length).
+    DownloadProgress { done: u64, total: Option<u64> },
+    Verifying,
+    Done
+}
+
+impl InstallEvent {
+    /// Get the stage's progress as a fraction (0 to 1), or `None` if the total is unknown (e.g.
+    /// a chunked download without a content length), so a UI can show an indeterminate progress
+    /// bar instead.
+    pub fn progress(&self) -> Option<f64> {
+        let (done, total) = match *self {
+            InstallEvent::DownloadProgress { done, total } => (done, total?),
+            _ => return None
+        };
+
+        if total == 0 {
+            return None;
+        }
+
+        Some((done as f64 / total as f64).min(1.0))
+    }
+}
\ No newline at end of file
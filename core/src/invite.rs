@@ -4,8 +4,11 @@ use serde_json::Value;
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Invite {
     pub id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
+    #[serde(alias = "fromUserId")]
     pub from_user_id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub details: Value,
     pub date: String
@@ -0,0 +1,44 @@
+use crate::deep_link::{DeepLink, DEEP_LINK_SCHEME};
+
+/// How a binary was launched: by the OS through the `frogworks://` URL scheme (registered by the
+/// installer), or directly (e.g. from a terminal).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchContext {
+    DeepLink(DeepLink),
+    /// Launched through the URL scheme, but with a link that isn't a valid deep link.
+    InvalidDeepLink(String),
+    Interactive
+}
+
+impl LaunchContext {
+    pub fn is_deep_link(&self) -> bool {
+        !matches!(self, LaunchContext::Interactive)
+    }
+}
+
+/// Work out how a binary was launched from its arguments (excluding the program name). The OS
+/// passes the link as the first argument when a `frogworks://` link is opened.
+///
+/// # Arguments
+/// * `args` - The command line arguments, excluding the program name
+pub fn parse_launch_context(args: &[String]) -> LaunchContext {
+    let first_arg: &str = match args.first() {
+        Some(first_arg) => first_arg.trim(),
+        None => return LaunchContext::Interactive
+    };
+
+    let is_link: bool = first_arg.split_once("://")
+        .is_some_and(|(scheme, _)| scheme.eq_ignore_ascii_case(DEEP_LINK_SCHEME));
+
+    if !is_link {
+        return LaunchContext::Interactive;
+    }
+
+    // Schemes are case-insensitive, but `DeepLink::parse` expects the lowercase form.
+    let link: String = format!("{}{}", DEEP_LINK_SCHEME, &first_arg[DEEP_LINK_SCHEME.len()..]);
+
+    match DeepLink::parse(&link) {
+        Some(deep_link) => LaunchContext::DeepLink(deep_link),
+        None => LaunchContext::InvalidDeepLink(first_arg.to_string())
+    }
+}
\ No newline at end of file
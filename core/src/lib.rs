@@ -1,62 +1,148 @@
-use std::borrow::Cow;
 use std::ffi::OsString;
-use std::fs::{read_to_string, File, OpenOptions};
-use std::io::{Error, Write};
-use std::path::PathBuf;
+use std::fs::{create_dir_all, metadata, read_to_string, File, OpenOptions};
+use std::io::{Error, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
 use bytes::Bytes;
-use gethostname::gethostname;
-use reqwest::blocking::{Client, Response};
+use reqwest::blocking::{Client, Request, RequestBuilder, Response};
 use reqwest::blocking::multipart::Form;
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{Method, StatusCode};
 use serde::{Deserialize, Serialize};
-use serde_json::{from_str, json, to_string_pretty, Value};
+use serde::de::DeserializeOwned;
+use serde_json::{from_str, from_value, json, to_string_pretty, Value};
 use url::Url;
-use crate::api_error::APIError;
+use uuid::Uuid;
+use crate::api_error::{APIError, FieldError};
 use crate::application::Application;
 use crate::application_key::ApplicationKey;
-use crate::application_version::ApplicationVersion;
+use crate::application_version::{ApplicationVersion, NewApplicationVersion};
+use crate::batch::Batch;
+use crate::cache::{CachedResponse, ResponseCache};
+use crate::catalog::ApplicationDefinition;
 use crate::cloud_data::CloudData;
+use crate::dashboard::Dashboard;
 use crate::deposit::Deposit;
+use crate::device_info::DeviceInfo;
+use crate::diagnosis::{DiagnosticStage, Diagnosis};
+use crate::download::{is_safe_filename, resolve_download_path, CollisionPolicy, PartialFile};
+use crate::events::EventStream;
+use crate::form_fields::FormFields;
 use crate::friend::Friend;
+use crate::friend_presence::FriendPresence;
 use crate::friend_request::FriendRequest;
 use crate::iap::IAP;
 use crate::iap_record::IAPRecord;
+use crate::install_event::InstallEvent;
 use crate::invite::Invite;
-use crate::purchase::Purchase;
+use crate::photo::Photo;
+use crate::platform::{Platform, PlatformInfo};
+use crate::public_profile::PublicProfile;
+use crate::rate_limiter::RateLimiter;
+use crate::purchase::{Purchase, PurchaseKind};
+use crate::receipt::Receipt;
+use crate::revenue_report::RevenueReport;
 use crate::sale::Sale;
+use crate::server_error_code::ServerErrorCode;
+use crate::server_limits::ServerLimits;
 use crate::session::Session;
 use crate::transaction::Transaction;
 use crate::user::User;
+use crate::verification_status::VerificationStatus;
 
 pub mod activity;
 pub mod application;
 pub mod application_key;
 pub mod application_session;
 pub mod application_version;
+pub mod batch;
+pub mod cache;
+pub mod catalog;
 pub mod cloud_data;
+pub mod daemon_client;
+pub mod dashboard;
+pub mod deep_link;
 pub mod deposit;
+pub mod device_info;
+pub mod diagnosis;
+pub mod download;
+pub mod events;
+mod form_fields;
 pub mod friend;
+pub mod friend_presence;
 pub mod friend_request;
 pub mod iap;
 pub mod iap_record;
+pub mod list_field;
+pub mod install_event;
 pub mod invite;
+pub mod launch_context;
 pub mod photo;
+#[cfg(feature = "websocket")]
+pub mod presence;
+pub mod price;
+pub mod platform;
+pub mod public_profile;
 pub mod purchase;
+mod rate_limiter;
+pub mod receipt;
+pub mod revenue_report;
 pub mod sale;
+pub mod server_error_code;
+pub mod server_limits;
 pub mod session;
+pub mod timestamp;
 pub mod transaction;
 pub mod user;
+pub mod verification_status;
 pub mod api_error;
+pub mod models;
+pub mod prelude;
 
 pub type ApiResult<T> = Result<T, APIError>;
 
+/// The cloud data slot used when no slot is specified.
+pub const DEFAULT_CLOUD_DATA_SLOT: &str = "default";
+
+/// The release branch used when no branch is specified.
+pub const DEFAULT_BRANCH: &str = "stable";
+
+/// A callback invoked after each request with the request's method and URL, the time taken, and
+/// the outcome (the response's status code, or the error that prevented a response).
+pub type RequestObserver =
+    Arc<dyn Fn(&Method, &Url, Duration, Result<StatusCode, &APIError>) + Send + Sync>;
+
+/// How long the connectivity self-test waits for a TCP connection to each address.
+const DIAGNOSE_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The name of the offline cache's file in the data directory (see `with_offline_cache`).
+pub const OFFLINE_CACHE_FILENAME: &str = "offline-cache.json";
+
+/// The length of a session id. The server issues session ids (e.g. from `login`) as 128-bit
+/// values, hex-encoded as 32 characters; if the server's format changes, so must this.
+pub const SESSION_ID_LENGTH: usize = 32;
+
+/// Check that a string looks like a session id (`SESSION_ID_LENGTH` hexadecimal characters).
+pub fn is_valid_session_id(s: &str) -> bool {
+    s.len() == SESSION_ID_LENGTH && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct EmailVerificationCheckResponse {
-    email_verified: bool
+    email_verified: bool,
+    // Older servers only send `email_verified`.
+    status: Option<VerificationStatus>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AvailabilityResponse {
+    available: bool
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -70,37 +156,95 @@ pub struct SessionAuthenticationResponse {
     user_id: Option<i32>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PingResponse {
+    pub message: String,
+    pub timestamp: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApiVersionResponse {
+    version: String
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ApplicationCreationResponse {
     details: String,
     application_id: i32
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ValidationErrorResponse {
+    errors: Vec<FieldError>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetApplicationsResponse {
+    applications: Vec<Application>
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetApplicationVersionsResponse {
     versions: Vec<ApplicationVersion>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetAvailablePlatformsResponse {
+    platforms: Vec<Platform>
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetAllSalesResponse {
     sales: Vec<Sale>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ActiveSale {
+    application: Application,
+    sale: Sale
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetActiveSalesResponse {
+    sales: Vec<ActiveSale>
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetUserTransactionsResponse {
     transactions: Vec<Transaction>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetBalanceResponse {
+    #[serde(with = "crate::price")]
+    balance: f32
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetUserPurchasesResponse {
+    purchases: Vec<Purchase>
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetUserApplicationKeysResponse {
     application_keys: Vec<ApplicationKey>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RevokeApplicationKeysResponse {
+    revoked: u64
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetIAPRecordsResponse {
     iap_records: Vec<IAPRecord>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CountIAPRecordsResponse {
+    count: u64
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetFriendRequestsResponse {
     friend_requests: Vec<FriendRequest>
@@ -111,6 +255,16 @@ pub struct GetFriendsResponse {
     friends: Vec<Friend>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetFollowedDevelopersResponse {
+    developers: Vec<PublicProfile>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PlaytimeResponse {
+    total_seconds: i64
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetInvitesResponse {
     invites: Vec<Invite>
@@ -121,18 +275,70 @@ pub struct GetIAPsResponse {
     iaps: Vec<IAP>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetGenresResponse {
+    genres: Vec<String>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetTagsResponse {
+    tags: Vec<String>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetUserBranchResponse {
+    branch: String
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetApplicationMediaResponse {
+    media: Vec<Photo>
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DeleteOtherSessionsResponse {
+    deleted: u64
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetCloudDataSlotsResponse {
+    slots: Vec<String>
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetUserSessionsResponse {
     sessions: Vec<Session>
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MaintenanceResponse {
+    message: String,
+    retry_after: Option<u64>
+}
+
+/// A client for the Frogworks backend. Clones share the underlying HTTP connection pool, so they
+/// are cheap to create (e.g. to target another backend with `with_base_url`).
+#[derive(Clone)]
 pub struct ApiService {
     base_url: Url,
     server_port: u16,
-    session_id: Option<String>,
+    session_id: Arc<RwLock<Option<String>>>,
     user_agent_string: Option<String>,
     version: String,
-    client: Client
+    client: Client,
+    observer: Option<RequestObserver>,
+    cache: Option<Arc<ResponseCache>>,
+    max_response_size: Option<u64>,
+    json_bodies: bool,
+    idempotency: bool,
+    reauth: Option<(String, String)>,
+    reauthenticating: Arc<AtomicBool>,
+    balance_precheck: bool,
+    known_balance: Arc<RwLock<Option<f32>>>,
+    platform_headers: bool,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    server_limits: Arc<RwLock<Option<ServerLimits>>>,
+    known_genres: Arc<RwLock<Option<Vec<String>>>>
 }
 
 impl ApiService {
@@ -140,10 +346,23 @@ impl ApiService {
         Self {
             base_url: Url::from_str(base_url.as_str()).unwrap(),
             server_port: 80,
-            session_id: None,
+            session_id: Arc::new(RwLock::new(None)),
             user_agent_string: None,
             version: String::from("1.0"),
-            client: Client::new()
+            client: Client::new(),
+            observer: None,
+            cache: None,
+            max_response_size: None,
+            json_bodies: false,
+            idempotency: true,
+            reauth: None,
+            reauthenticating: Arc::new(AtomicBool::new(false)),
+            balance_precheck: false,
+            known_balance: Arc::new(RwLock::new(None)),
+            platform_headers: false,
+            rate_limiter: None,
+            server_limits: Arc::new(RwLock::new(None)),
+            known_genres: Arc::new(RwLock::new(None))
         }
     }
 
@@ -152,11 +371,48 @@ impl ApiService {
         self
     }
 
+    /// Changes the base URL requests are sent to. If offline mode is enabled, the cache is
+    /// replaced with an empty one, so responses from the previous backend are never served (as
+    /// are the remembered genres).
+    ///
+    /// # Arguments
+    /// * `base_url` - The new base URL
+    pub fn with_base_url(mut self, base_url: String) -> ApiResult<Self> {
+        self.base_url = Url::from_str(base_url.as_str())?;
+        self.cache = self.cache.map(|cache| Arc::new(cache.emptied()));
+        self.known_genres = Arc::new(RwLock::new(None));
+
+        Ok(self)
+    }
+
+    /// Authenticate requests with a session id. The id isn't checked (see
+    /// `try_with_authentication`); one that can't be sent as a header is left out of requests, so
+    /// they fail as unauthenticated.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session id
     pub fn with_authentication(mut self, session_id: String) -> Self {
-        self.session_id = Some(String::from(session_id));
+        self.clear_cache_if_session_changes(&session_id);
+
+        self.session_id = Arc::new(RwLock::new(Some(session_id)));
+        self.known_balance = Arc::new(RwLock::new(None));
+
         self
     }
 
+    /// Like `with_authentication`, but malformed ids (e.g. empty, or pasted with whitespace; see
+    /// `is_valid_session_id`) are rejected with `APIError::BadRequest`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session id
+    pub fn try_with_authentication(self, session_id: String) -> ApiResult<Self> {
+        if !is_valid_session_id(&session_id) {
+            return Err(APIError::BadRequest(format!("Invalid session id: {:?}", session_id)));
+        }
+
+        Ok(self.with_authentication(session_id))
+    }
+
     pub fn with_user_agent(mut self, user_agent_string: String) -> Self {
         self.user_agent_string = Some(user_agent_string);
         self
@@ -167,6 +423,200 @@ impl ApiService {
         self
     }
 
+    /// Sets how long a request may take (the default is 30 seconds).
+    ///
+    /// # Arguments
+    /// * `timeout` - The timeout, or `None` to wait indefinitely
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> ApiResult<Self> {
+        self.client = Client::builder()
+            .timeout(timeout)
+            .build()?;
+
+        Ok(self)
+    }
+
+    /// Checks that the server's API version is compatible with the specified one (i.e. has the
+    /// same major version), failing with `APIError::IncompatibleVersion` if it isn't.
+    ///
+    /// # Arguments
+    /// * `version` - The API version this client was written against (e.g. "1.2")
+    pub fn with_required_api_version(self, version: String) -> ApiResult<Self> {
+        let server_version: String = self.get_api_version()?;
+
+        let major = |version: &str| version.split('.').next().unwrap_or("").trim().to_string();
+
+        if major(&version) != major(&server_version) {
+            return Err(APIError::IncompatibleVersion { client: version, server: server_version });
+        }
+
+        Ok(self)
+    }
+
+    /// Registers a callback to be invoked after every request (useful for metrics and tracing).
+    ///
+    /// # Arguments
+    /// * `observer` - Called with the request's method and URL (the endpoint being its path), the
+    ///   elapsed time, and the request's outcome
+    pub fn with_observer<F>(mut self, observer: F) -> Self
+    where
+        F: Fn(&Method, &Url, Duration, Result<StatusCode, &APIError>) + Send + Sync + 'static
+    {
+        self.observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Enables offline mode: successful responses from offline-capable requests (e.g.
+    /// `get_application_offline`) are cached, and served if the server later becomes unreachable.
+    ///
+    /// # Arguments
+    /// * `ttl` - How long a cached response may be served for
+    pub fn with_offline_mode(mut self, ttl: Duration) -> Self {
+        self.cache = Some(Arc::new(ResponseCache::new(ttl)));
+        self
+    }
+
+    /// Like `with_offline_mode`, but the cache is kept in the data directory (as
+    /// `OFFLINE_CACHE_FILENAME`), so it's still available after a restart. Cached responses
+    /// belong to the authenticated user (see `get_me`), and are cleared when the session changes.
+    ///
+    /// # Arguments
+    /// * `data_dir` - The folder to keep the cache in (created if it doesn't exist)
+    /// * `ttl` - How long a cached response may be served for
+    pub fn with_offline_cache(mut self, data_dir: &Path, ttl: Duration) -> Self {
+        let path: PathBuf = data_dir.join(OFFLINE_CACHE_FILENAME);
+
+        self.cache = Some(Arc::new(ResponseCache::open(path, ttl)));
+        self
+    }
+
+    /// Limits the size of the responses that will be read (excluding file downloads); larger
+    /// responses fail with `APIError::ResponseTooLarge`.
+    ///
+    /// # Arguments
+    /// * `bytes` - The maximum response size, in bytes
+    pub fn with_max_response_size(mut self, bytes: u64) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Send text-only request fields as a JSON body rather than a multipart form (file uploads are
+    /// always sent as multipart forms).
+    pub fn with_json_bodies(mut self, json_bodies: bool) -> Self {
+        self.json_bodies = json_bodies;
+        self
+    }
+
+    /// Whether to send an `Idempotency-Key` header with purchases, so the server can deduplicate
+    /// retried requests (enabled by default).
+    pub fn with_idempotency(mut self, idempotency: bool) -> Self {
+        self.idempotency = idempotency;
+        self
+    }
+
+    /// Log in again with the specified credentials if a request fails with 401 (e.g. because the
+    /// session expired), then retry the request once with the new session.
+    ///
+    /// # Arguments
+    /// * `username` - The user's username
+    /// * `password` - The user's password
+    pub fn with_reauth(mut self, username: String, password: String) -> Self {
+        self.reauth = Some((username, password));
+        self
+    }
+
+    /// Check the authenticated user's balance against an IAP's price before purchasing it,
+    /// failing locally with `APIError::InsufficientBalance` if it can't be afforded. The check
+    /// only runs when the balance is already known (from `get_me`), so it never costs an extra
+    /// request just to fetch the balance.
+    pub fn with_balance_precheck(mut self, balance_precheck: bool) -> Self {
+        self.balance_precheck = balance_precheck;
+        self
+    }
+
+    /// Send the current platform (`X-Platform`) and this crate's version (`X-Client-Version`) with
+    /// every request, for per-platform analytics (disabled by default).
+    pub fn with_platform_headers(mut self, platform_headers: bool) -> Self {
+        self.platform_headers = platform_headers;
+        self
+    }
+
+    /// Pace outgoing requests to at most `requests_per_second` on average (allowing short bursts),
+    /// blocking before a request when the limit has been reached, so that bulk code doesn't get
+    /// rate limited by the server. Clones made after this share the limit. Disabled by default.
+    ///
+    /// # Arguments
+    /// * `requests_per_second` - The average number of requests allowed per second
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.rate_limiter = if requests_per_second > 0.0 && requests_per_second.is_finite() {
+            Some(Arc::new(RateLimiter::new(requests_per_second)))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Clear the offline cache if the session is being replaced by another one, so the previous
+    /// user's responses are never served to the next.
+    fn clear_cache_if_session_changes(&self, session_id: &str) {
+        let changed: bool = self.session_id.read().unwrap().as_ref()
+            .is_some_and(|current| current != session_id);
+
+        if changed {
+            self.clear_cache();
+        }
+    }
+
+    fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Remember the user the offline cache's responses belong to.
+    fn set_cache_user(&self, user_id: i32) {
+        if let Some(cache) = &self.cache {
+            cache.set_user_id(user_id);
+        }
+    }
+
+    /// Remember the authenticated user's balance for the purchase pre-check (`None` once it may
+    /// be out of date).
+    fn set_known_balance(&self, balance: Option<f32>) {
+        *self.known_balance.write().unwrap() = balance;
+    }
+
+    /// Check a value against the server's limits. Only runs when the limits are already known
+    /// (from `get_server_limits`), so it never costs an extra request.
+    fn check_limits<F>(&self, check: F) -> Option<FieldError>
+    where
+        F: FnOnce(&ServerLimits) -> Option<FieldError>
+    {
+        self.server_limits.read().unwrap().as_ref().and_then(check)
+    }
+
+    /// Check that a list field can be sent: multipart forms send lists comma-separated, so their
+    /// values can't contain commas (JSON bodies send lists as arrays, so any value can be sent).
+    fn check_list(&self, field: &str, values: &[String]) -> Option<FieldError> {
+        if self.json_bodies || !values.iter().any(|value| value.contains(',')) {
+            return None;
+        }
+
+        Some(FieldError {
+            field: field.to_string(),
+            message: String::from("Values can't contain commas unless JSON bodies are enabled.")
+        })
+    }
+
+    /// Get the known genres, fetching them the first time. Returns `None` if they couldn't be
+    /// fetched, in which case the server is left to validate genres itself.
+    fn known_genres(&self) -> Option<Vec<String>> {
+        if let Some(genres) = self.known_genres.read().unwrap().as_ref() {
+            return Some(genres.clone());
+        }
+
+        self.get_all_genres().ok()
+    }
+
     fn get_headers(&self) -> HeaderMap {
         let mut headers: HeaderMap = HeaderMap::new();
 
@@ -178,120 +628,553 @@ impl ApiService {
         }
 
 
-        if let Some(session_id) = &self.session_id {
-            headers.insert("Session-Id", HeaderValue::from_str(session_id).unwrap());
+        // An id that isn't a valid header value is left out, rather than panicking.
+        if let Some(session_id) = self.get_session_id() {
+            if let Ok(session_id) = HeaderValue::from_str(&session_id) {
+                headers.insert("Session-Id", session_id);
+            }
+        }
+
+        if self.platform_headers {
+            headers.insert("X-Platform", HeaderValue::from_static(Platform::current().as_str()));
+            headers.insert("X-Client-Version", HeaderValue::from_static(env!("CARGO_PKG_VERSION")));
         }
 
         headers
     }
 
+    /// Get the headers for a request that moves money, including an idempotency key (if enabled):
+    /// the caller's key for this operation, or a fresh one. The key is chosen once per call, so
+    /// it is shared by any retries of the request, but never by another operation. A key that
+    /// can't be sent as a header fails with `APIError::BadRequest`.
+    fn get_idempotent_headers(&self, idempotency_key: Option<String>) -> ApiResult<HeaderMap> {
+        let mut headers: HeaderMap = self.get_headers();
+
+        if self.idempotency {
+            let idempotency_key: String = idempotency_key
+                .unwrap_or_else(|| Uuid::new_v4().to_string());
+            let header_value: HeaderValue = HeaderValue::from_str(&idempotency_key).map_err(|_| {
+                APIError::BadRequest(format!("Invalid idempotency key: {:?}", idempotency_key))
+            })?;
+
+            headers.insert("Idempotency-Key", header_value);
+        }
+
+        Ok(headers)
+    }
+
     fn get_url_for(&self, path: &str) -> Url {
         self.base_url.join(path).unwrap()
     }
 
-    fn get_platform(&self) -> String {
-        String::from(if cfg!(target_os = "windows") {
-            "windows"
-        } else if cfg!(target_os = "linux") {
-            "linux"
-        } else if cfg!(target_os = "macos") {
-            "macos"
+    /// Attach text-only fields to a request, either as a JSON body or a multipart form.
+    fn with_fields(&self, request: RequestBuilder, fields: FormFields) -> RequestBuilder {
+        if self.json_bodies {
+            request.json(&fields.into_json())
         } else {
-            "unknown"
-        })
+            let (content_type, body) = fields.into_multipart();
+
+            request.header(CONTENT_TYPE, content_type).body(body)
+        }
     }
 
-    fn get_mac_address(&self) -> Result<Option<String>, mac_address::MacAddressError> {
-        match mac_address::get_mac_address() {
-            Ok(Some(mac_address)) => {
-                Ok(Some(format!("{}", mac_address)))
-            },
-            Ok(None) => Ok(None),
-            Err(err) => Err(err)
+    /// Sends a request. If re-authentication is enabled (see `with_reauth`) and the request fails
+    /// with 401, logs in again and retries the request once.
+    fn send(&self, request_builder: RequestBuilder) -> ApiResult<Response> {
+        // Keep a copy of the request, in case it has to be retried.
+        let retry_builder: Option<RequestBuilder> = match self.reauth {
+            Some(_) => request_builder.try_clone(),
+            None => None
+        };
+
+        let response: Response = self.execute(request_builder)?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            if let Some(retry_builder) = retry_builder {
+                if self.reauthenticate()? {
+                    // Replace the stale session id header.
+                    return self.execute(retry_builder.headers(self.get_headers()));
+                }
+            }
         }
+
+        Ok(response)
     }
 
-    pub fn authenticated(&self) -> bool {
-        self.session_id.is_some()
+    /// Log in again with the re-authentication credentials, replacing the session id. Returns
+    /// false (without logging in) if re-authentication isn't enabled or is already in progress
+    /// (e.g. the login request itself was rejected).
+    fn reauthenticate(&self) -> ApiResult<bool> {
+        let (username, password) = match &self.reauth {
+            Some(credentials) => credentials,
+            None => return Ok(false)
+        };
+
+        if self.reauthenticating.swap(true, Ordering::SeqCst) {
+            return Ok(false);
+        }
+
+        let result: ApiResult<String> = self.login(username.clone(), password.clone());
+        self.reauthenticating.store(false, Ordering::SeqCst);
+
+        // The new session belongs to the same user, so the offline cache is kept.
+        *self.session_id.write().unwrap() = Some(result?);
+
+        Ok(true)
     }
 
-    /// Pings the server (used for connectivity testing).
-    pub fn ping(&self) -> ApiResult<Value> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/ping");
+    /// Executes a request, reporting its timing and outcome to the observer (if any). A 503
+    /// response (the server is down for maintenance) is returned as `APIError::Maintenance`.
+    fn execute(&self, request_builder: RequestBuilder) -> ApiResult<Response> {
+        let request: Request = request_builder.build()?;
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .send()?;
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire();
+        }
 
-        if response.status() != StatusCode::OK {
-            return Err(APIError::UnhandledStatusCode(response.status()))
+        let method: Method = request.method().clone();
+        let url: Url = request.url().clone();
+
+        let start: Instant = Instant::now();
+        let result: ApiResult<Response> = self.client.execute(request).map_err(APIError::from);
+        let duration: Duration = start.elapsed();
+
+        if let Some(observer) = &self.observer {
+            observer(&method, &url, duration, result.as_ref().map(|response| response.status()));
         }
 
-        Ok(from_str(&response.text()?)?)
+        match result {
+            Ok(response) if response.status() == StatusCode::SERVICE_UNAVAILABLE =>
+                Err(self.read_maintenance(response)),
+            result => result
+        }
     }
 
-    /// Requests a verification code be sent to a specified email address.
-    ///
-    /// # Arguments
-    /// * `email_address` The email address to send the verification code to
-    pub fn request_email_verification(&self, email_address: String) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url = self.get_url_for("/api/email-verification/request");
+    /// Parse a maintenance (503) response. The message and ETA are taken from a JSON body if there
+    /// is one, with the `Retry-After` header taking precedence for the ETA.
+    fn read_maintenance(&self, response: Response) -> APIError {
+        let header_retry_after: Option<u64> = self.get_retry_after(&response)
+            .map(|retry_after| retry_after.as_secs());
 
-        let form: Form = Form::new()
-            .text("email_address", email_address);
+        let body: String = match self.read_text(response) {
+            Ok(body) => body,
+            Err(err) => return err
+        };
 
-        let response: Response = self.client
-            .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        let (message, body_retry_after) = match from_str::<MaintenanceResponse>(&body) {
+            Ok(maintenance) => (maintenance.message, maintenance.retry_after),
+            Err(_) => (body, None)
+        };
 
-        match response.status() {
-            StatusCode::OK => Ok(()),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
+        APIError::Maintenance {
+            retry_after: header_retry_after.or(body_retry_after),
+            message
         }
     }
 
-    /// Checks a verification code against the one in the database for a specific email address (if
-    /// any).
-    ///
-    /// # Arguments
-    /// * `email_address` - The user's email address
-    /// * `verification_code` - The email verification code
-    pub fn check_email_verification(&self, email_address: String,
-                                    verification_code: i32) -> ApiResult<bool> {
+    /// Read a response's body as text, enforcing the maximum response size (if any). The body is
+    /// always decoded as UTF-8 (whatever charset the server declares), and invalid sequences fail
+    /// with `APIError::InvalidUtf8` rather than being silently replaced.
+    fn read_text(&self, response: Response) -> ApiResult<String> {
+        let max_response_size: u64 = match self.max_response_size {
+            Some(max_response_size) => max_response_size,
+            None => return self.decode_text(response.bytes()?.to_vec())
+        };
+
+        // Bail out early if the server says the response is too large.
+        if response.content_length().is_some_and(|length| length > max_response_size) {
+            return Err(APIError::ResponseTooLarge);
+        }
+
+        // Cap the read, in case the content length is missing or wrong.
+        let mut body: Vec<u8> = Vec::new();
+        response.take(max_response_size + 1).read_to_end(&mut body)?;
+
+        if body.len() as u64 > max_response_size {
+            return Err(APIError::ResponseTooLarge);
+        }
+
+        self.decode_text(body)
+    }
+
+    /// Decode a body as UTF-8, skipping the byte order mark (if there is one).
+    fn decode_text(&self, mut body: Vec<u8>) -> ApiResult<String> {
+        if body.starts_with(b"\xEF\xBB\xBF") {
+            body.drain(..3);
+        }
+
+        Ok(String::from_utf8(body)?)
+    }
+
+    /// Parse a response's body as JSON; an empty body is treated as `null` (so it parses into
+    /// `Value::Null`, `()`, or `None` rather than failing).
+    fn read_json<T: DeserializeOwned>(&self, response: Response) -> ApiResult<T> {
+        let text: String = self.read_text(response)?;
+
+        if text.trim().is_empty() {
+            return Ok(from_str("null")?);
+        }
+
+        Ok(from_str(&text)?)
+    }
+
+    /// Get the delay the server asked for (in the `Retry-After` header) before trying again.
+    fn get_retry_after(&self, response: &Response) -> Option<Duration> {
+        let retry_after: &HeaderValue = response.headers().get("Retry-After")?;
+        let seconds: u64 = retry_after.to_str().ok()?.trim().parse().ok()?;
+
+        Some(Duration::from_secs(seconds))
+    }
+
+    /// Build and send an authenticated request to the specified path (with the fields, if there
+    /// are any). The error statuses shared by every endpoint are mapped to errors, and anything
+    /// other than 200 is unhandled.
+    fn request_fields(&self, method: Method, path: &str,
+                      fields: FormFields) -> ApiResult<Response> {
         let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/email-verification/check");
+        let url: Url = self.get_url_for(path);
 
-        let form: Form = Form::new()
-            .text("email_address", email_address)
-            .text("verification_code", verification_code.to_string());
+        let mut request: RequestBuilder = self.client
+            .request(method, url.as_str())
+            .headers(headers);
 
-        let response: Response = self.client
-            .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        if !fields.is_empty() {
+            request = self.with_fields(request, fields);
+        }
 
-        match response.status() {
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let verification_response: EmailVerificationCheckResponse =
-                    from_str(&response.text()?)?;
+        let response: Response = self.send(request)?;
 
-                Ok(verification_response.email_verified)
-            },
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::OK => Ok(response),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
-    /// Attempts to create a new user account.
-    ///
-    /// # Arguments
+    /// Send a GET request and parse the response.
+    fn get_json<T: DeserializeOwned>(&self, path: &str, params: FormFields) -> ApiResult<T> {
+        let response: Response = self.request_fields(Method::GET, path, params)?;
+
+        self.read_json(response)
+    }
+
+    fn post_form(&self, path: &str, form: FormFields) -> ApiResult<Response> {
+        self.request_fields(Method::POST, path, form)
+    }
+
+    fn put_form(&self, path: &str, form: FormFields) -> ApiResult<Response> {
+        self.request_fields(Method::PUT, path, form)
+    }
+
+    fn delete_form(&self, path: &str, params: FormFields) -> ApiResult<Response> {
+        self.request_fields(Method::DELETE, path, params)
+    }
+
+    /// Run a request, caching its result; if the server can't be reached, fall back to the cached
+    /// result (if any).
+    fn with_offline_fallback<T, F>(&self, key: String,
+                                   request_logic: F) -> ApiResult<CachedResponse<T>>
+    where
+        F: FnOnce() -> ApiResult<T>,
+        T: Serialize + DeserializeOwned
+    {
+        match request_logic() {
+            Ok(value) => {
+                if let Some(cache) = &self.cache {
+                    cache.insert(key, &value);
+                }
+
+                Ok(CachedResponse { value, offline: false })
+            },
+            Err(APIError::ReqwestError(err)) if err.is_connect() || err.is_timeout() => {
+                match self.cache.as_ref().and_then(|cache| cache.get(&key)) {
+                    Some(value) => Ok(CachedResponse { value, offline: true }),
+                    None => Err(APIError::ReqwestError(err))
+                }
+            },
+            Err(err) => Err(err)
+        }
+    }
+
+    fn get_architecture(&self) -> String {
+        String::from(std::env::consts::ARCH)
+    }
+
+    fn get_cloud_data_slot(&self, slot: Option<String>) -> String {
+        slot.unwrap_or_else(|| String::from(DEFAULT_CLOUD_DATA_SLOT))
+    }
+
+    fn get_branch(&self, branch: Option<String>) -> String {
+        branch.unwrap_or_else(|| String::from(DEFAULT_BRANCH))
+    }
+
+    /// Get the current machine's platform details (OS, architecture, and OS version).
+    pub fn get_platform_detailed(&self) -> PlatformInfo {
+        PlatformInfo::current()
+    }
+
+    pub fn authenticated(&self) -> bool {
+        self.get_session_id().is_some()
+    }
+
+    fn get_session_id(&self) -> Option<String> {
+        self.session_id.read().unwrap().clone()
+    }
+
+    /// Pings the server (used for connectivity testing).
+    pub fn ping(&self) -> ApiResult<PingResponse> {
+        Ok(from_value(self.ping_raw()?)?)
+    }
+
+    /// Like `ping`, but returns the server's response as-is.
+    pub fn ping_raw(&self) -> ApiResult<Value> {
+        let headers: HeaderMap = self.get_headers();
+        let url: Url = self.get_url_for("/api/ping");
+
+        let request: RequestBuilder = self.client
+            .get(url.as_str())
+            .headers(headers);
+
+        let response: Response = self.send(request)?;
+
+        if response.status() != StatusCode::OK {
+            return Err(APIError::UnhandledStatusCode(response.status()))
+        }
+
+        self.read_json(response)
+    }
+
+    /// Run a connectivity self-test: check the base URL, resolve its host, connect to it over TCP,
+    /// and ping the server. The self-test stops at the first stage that fails, and the report
+    /// includes how long each stage took.
+    pub fn diagnose(&self) -> Diagnosis {
+        let mut diagnosis: Diagnosis = Diagnosis::default();
+
+        // Make sure the base URL has a host and port to connect to.
+        let start: Instant = Instant::now();
+        let host: String = self.base_url.host_str().unwrap_or_default().to_string();
+
+        let port: u16 = match self.base_url.port_or_known_default() {
+            Some(port) if !host.is_empty() => port,
+            _ => {
+                let detail: String = format!("{} has no host or port", self.base_url);
+                diagnosis.fail(DiagnosticStage::Url, start, detail);
+
+                return diagnosis;
+            }
+        };
+
+        diagnosis.pass(DiagnosticStage::Url, start, format!("{}:{}", host, port));
+
+        // Resolve the host.
+        let start: Instant = Instant::now();
+
+        let addresses: Vec<SocketAddr> = match (host.as_str(), port).to_socket_addrs() {
+            Ok(addresses) => addresses.collect(),
+            Err(err) => {
+                diagnosis.fail(DiagnosticStage::Dns, start, err.to_string());
+
+                return diagnosis;
+            }
+        };
+
+        if addresses.is_empty() {
+            diagnosis.fail(DiagnosticStage::Dns, start, format!("{} has no addresses", host));
+
+            return diagnosis;
+        }
+
+        let resolved: Vec<String> = addresses.iter()
+            .map(|address| address.ip().to_string())
+            .collect();
+
+        diagnosis.pass(DiagnosticStage::Dns, start, resolved.join(", "));
+
+        // Connect to the first address that accepts the connection.
+        let start: Instant = Instant::now();
+        let mut errors: Vec<String> = Vec::new();
+
+        let connected: Option<&SocketAddr> = addresses.iter().find(|address| {
+            match TcpStream::connect_timeout(address, DIAGNOSE_CONNECT_TIMEOUT) {
+                Ok(_) => true,
+                Err(err) => {
+                    errors.push(format!("{}: {}", address, err));
+
+                    false
+                }
+            }
+        });
+
+        match connected {
+            Some(address) => {
+                diagnosis.pass(DiagnosticStage::Tcp, start, format!("Connected to {}", address));
+            },
+            None => {
+                diagnosis.fail(DiagnosticStage::Tcp, start, errors.join(", "));
+
+                return diagnosis;
+            }
+        }
+
+        // Make a round trip to the server.
+        let start: Instant = Instant::now();
+
+        match self.ping() {
+            Ok(ping) => diagnosis.pass(DiagnosticStage::Ping, start, ping.message),
+            Err(err) => diagnosis.fail(DiagnosticStage::Ping, start, err.to_string())
+        }
+
+        diagnosis
+    }
+
+    /// Get the limits the server enforces (e.g. the maximum upload size and the allowed prices).
+    /// The limits are remembered, so that later uploads and prices are checked before they're
+    /// sent, failing with `APIError::Validation` instead of a round trip to the server.
+    pub fn get_server_limits(&self) -> ApiResult<ServerLimits> {
+        let limits: ServerLimits = self.get_json("/api/limits", FormFields::new())?;
+
+        *self.server_limits.write().unwrap() = Some(limits.clone());
+
+        Ok(limits)
+    }
+
+    /// Get the server's API version (e.g. "1.2").
+    pub fn get_api_version(&self) -> ApiResult<String> {
+        let headers: HeaderMap = self.get_headers();
+        let url: Url = self.get_url_for("/api/version");
+
+        let request: RequestBuilder = self.client
+            .get(url.as_str())
+            .headers(headers);
+
+        let response: Response = self.send(request)?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let version_response: ApiVersionResponse = self.read_json(response)?;
+
+                Ok(version_response.version)
+            },
+            _ => Err(APIError::UnhandledStatusCode(response.status()))
+        }
+    }
+
+    /// Requests a verification code be sent to a specified email address.
+    /// <br>
+    /// If codes are being requested too often, this fails with `APIError::RateLimited` (including
+    /// how long to wait before requesting another code, if the server specified it).
+    ///
+    /// # Arguments
+    /// * `email_address` The email address to send the verification code to
+    pub fn request_email_verification(&self, email_address: String) -> ApiResult<()> {
+        let headers: HeaderMap = self.get_headers();
+        let url = self.get_url_for("/api/email-verification/request");
+
+        let form: FormFields = FormFields::new()
+            .text("email_address", email_address);
+
+        let request: RequestBuilder = self.client
+            .post(url.as_str())
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
+
+        match response.status() {
+            StatusCode::OK => Ok(()),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::TOO_MANY_REQUESTS => Err(APIError::RateLimited {
+                retry_after: self.get_retry_after(&response)
+            }),
+            _ => Err(APIError::UnhandledStatusCode(response.status()))
+        }
+    }
+
+    /// Checks a verification code against the one in the database for a specific email address (if
+    /// any), telling apart a correct, incorrect, or expired code and an already-verified address.
+    ///
+    /// # Arguments
+    /// * `email_address` - The user's email address
+    /// * `verification_code` - The email verification code
+    pub fn check_email_verification(&self, email_address: String,
+                                    verification_code: i32) -> ApiResult<VerificationStatus> {
+        let headers: HeaderMap = self.get_headers();
+        let url: Url = self.get_url_for("/api/email-verification/check");
+
+        let form: FormFields = FormFields::new()
+            .text("email_address", email_address)
+            .text("verification_code", verification_code.to_string());
+
+        let request: RequestBuilder = self.client
+            .post(url.as_str())
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
+
+        match response.status() {
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::OK => {
+                let verification_response: EmailVerificationCheckResponse =
+                    self.read_json(response)?;
+
+                Ok(verification_response.status.unwrap_or(
+                    if verification_response.email_verified {
+                        VerificationStatus::Verified
+                    } else {
+                        VerificationStatus::Incorrect
+                    }
+                ))
+            },
+            _ => Err(APIError::UnhandledStatusCode(response.status()))
+        }
+    }
+
+    /// Check whether a username is still available, so a registration form can flag it before
+    /// submitting.
+    ///
+    /// # Arguments
+    /// * `username` - The username to check
+    pub fn check_username_available(&self, username: String) -> ApiResult<bool> {
+        self.check_availability("username", username)
+    }
+
+    /// Check whether an email address isn't already in use by another account.
+    ///
+    /// # Arguments
+    /// * `email` - The email address to check
+    pub fn check_email_available(&self, email: String) -> ApiResult<bool> {
+        self.check_availability("email_address", email)
+    }
+
+    fn check_availability(&self, field: &str, value: String) -> ApiResult<bool> {
+        let headers: HeaderMap = self.get_headers();
+        let url: Url = self.get_url_for("/api/user/check-availability");
+
+        let form: FormFields = FormFields::new()
+            .text(field, value);
+
+        let request: RequestBuilder = self.client
+            .get(url.as_str())
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
+
+        match response.status() {
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::OK => {
+                // Parse the response.
+                let availability_response: AvailabilityResponse = self.read_json(response)?;
+
+                Ok(availability_response.available)
+            },
+            _ => Err(APIError::UnhandledStatusCode(response.status()))
+        }
+    }
+
+    /// Attempts to create a new user account.
+    ///
+    /// # Arguments
     /// * `username` - The user's preferred username
     /// * `name` - The user's name
     /// * `email_address` - The user's email address
@@ -302,25 +1185,25 @@ impl ApiService {
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/user/register");
 
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("username", username)
             .text("name", name)
             .text("email_address", email_address)
             .text("password", password)
             .text("email_verification_code", email_verification_code.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
             StatusCode::BAD_REQUEST => {
-                Err(APIError::BadRequest(response.text()?))
+                Err(APIError::BadRequest(self.read_text(response)?))
             },
             StatusCode::CREATED => {
-                Ok(from_str::<Value>(response.text()?.as_str())?)
+                self.read_json::<Value>(response)
             },
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
@@ -339,36 +1222,45 @@ impl ApiService {
     /// * `password` - The user's password.
     pub fn login(&self, username: String, password: String) -> ApiResult<String> {
         // Get the device details for the session (hostname, mac address, platform).
-        let hostname: OsString = gethostname();
-        let hostname_cow: Cow<str> = hostname.to_string_lossy();
-        let hostname_string: String = hostname_cow.into_owned();
-        let mac_address: String = self.get_mac_address().expect("Failed to get mac address.")
-            .expect("Failed to get mac address.");
-        let platform: String = self.get_platform();
+        self.login_with_device(username, password, DeviceInfo::collect())
+    }
 
+    /// Attempt to log in with the specified device details (rather than the host machine's);
+    /// creating a new session.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - The user's username.
+    /// * `password` - The user's password.
+    /// * `device` - The device details to associate with the session.
+    pub fn login_with_device(&self, username: String, password: String,
+                             device: DeviceInfo) -> ApiResult<String> {
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/user/login");
 
-        let form: Form = Form::new()
+        let mut form: FormFields = FormFields::new()
             .text("username", username)
             .text("password", password)
-            .text("hostname", hostname_string)
-            .text("mac_address", mac_address)
-            .text("platform", platform);
+            .text("hostname", device.hostname)
+            .text("platform", device.platform.to_string());
 
-        let response: Response = self.client
+        if let Some(mac_address) = device.mac_address {
+            form = form.text("mac_address", mac_address);
+        }
+
+        let request: RequestBuilder = self.client
             .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
             StatusCode::BAD_REQUEST => {
-                Err(APIError::BadRequest(response.text()?))
+                Err(APIError::BadRequest(self.read_text(response)?))
             },
             StatusCode::OK => {
                 // Login went okay; parse the response.
-                let response: LoginResponse = from_str(&response.text()?)?;
+                let response: LoginResponse = self.read_json(response)?;
 
                 Ok(response.session_id)
             },
@@ -376,105 +1268,387 @@ impl ApiService {
         }
     }
 
+    /// Requests a change of the current user's email address; a verification code is sent to the
+    /// new email address.
+    ///
+    /// # Arguments
+    /// * `new_email` - The email address to change to
+    pub fn request_email_change(&self, new_email: String) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("email_address", new_email);
+
+        self.post_form("/api/user/change-email/request", form)?;
+
+        Ok(())
+    }
+
+    /// Confirms a pending email address change using the verification code sent to the new
+    /// email address.
+    ///
+    /// # Arguments
+    /// * `new_email` - The email address being changed to
+    /// * `code` - The verification code sent to the new email address
+    pub fn confirm_email_change(&self, new_email: String, code: i32) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("email_address", new_email)
+            .text("verification_code", code.to_string());
+
+        self.put_form("/api/user/change-email/confirm", form)?;
+
+        Ok(())
+    }
+
     /// Attempt to fetch a user by their Frogworks ID.
     ///
     /// # Arguments
     /// * `identifier` The user's Frogworks ID
     pub fn get_user(&self, identifier: String,
                     identifier_type: String) -> ApiResult<User> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("identifier", identifier)
             .text("identifier_type", identifier_type);
 
-        let response: Response = self.client
+        self.get_json("/api/user/get", form)
+    }
+
+    /// Get everything a launcher's home screen needs (the user, their library, pending invites
+    /// and friend requests, and active sales) in a single request.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    pub fn get_dashboard(&self, user_id: i32) -> ApiResult<Dashboard> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
+
+        self.get_json("/api/dashboard", form)
+    }
+
+    /// Fetch another user's public profile (without any private details).
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    pub fn get_public_profile(&self, user_id: i32) -> ApiResult<PublicProfile> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
+
+        self.get_json("/api/user/public-profile", form)
+    }
+
+    /// Fetch the authenticated user's own profile (the session is resolved by the server).
+    pub fn get_me(&self) -> ApiResult<User> {
+        let user: User = self.get_json("/api/user/me", FormFields::new())?;
+
+        self.set_known_balance(user.balance);
+        self.set_cache_user(user.id);
+
+        Ok(user)
+    }
+
+    /// Like `get_me`, but serves the cached profile (flagged as offline) if the server can't be
+    /// reached. Requires offline mode to be enabled (see `with_offline_mode`).
+    pub fn get_me_offline(&self) -> ApiResult<CachedResponse<User>> {
+        self.with_offline_fallback(String::from("me"), || self.get_me())
+    }
+
+    /// Export all of the authenticated user's data (transactions, purchases, cloud data, friends,
+    /// and sessions) as an archive, streaming it to disk.
+    ///
+    /// # Arguments
+    /// * `dest` - The file to write the archive to, or a folder to write `frogworks-export.zip` to
+    pub fn export_account_data(&self, dest: PathBuf) -> ApiResult<PathBuf> {
+        let headers: HeaderMap = self.get_headers();
+        let url: Url = self.get_url_for("/api/user/export");
+
+        let request: RequestBuilder = self.client
             .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let mut response: Response = self.send(request)?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
             StatusCode::OK => {
-                // The request went okay; parse the result.
-                let user: User = from_str(&response.text()?)?;
-
-                Ok(user)
+                // Calculate the export filepath.
+                let filepath: PathBuf = if dest.is_dir() {
+                    dest.join("frogworks-export.zip")
+                } else {
+                    dest
+                };
+
+                // Stream the archive to the file (it may be too large to hold in memory).
+                let mut file: File = File::create(&filepath)?;
+                response.copy_to(&mut file)?;
+
+                Ok(filepath)
             },
             _ => Err(APIError::UnhandledStatusCode(response.status())),
         }
     }
 
+    /// Like `get_user`, but serves the cached user (flagged as offline) if the server can't be
+    /// reached. Requires offline mode to be enabled (see `with_offline_mode`).
+    pub fn get_user_offline(&self, identifier: String,
+                            identifier_type: String) -> ApiResult<CachedResponse<User>> {
+        let key: String = format!("user:{}:{}", identifier_type, identifier);
+
+        self.with_offline_fallback(key, || self.get_user(identifier, identifier_type))
+    }
+
+    /// Request developer status for the current user (allowing them to publish applications).
+    pub fn request_developer_status(&self) -> ApiResult<()> {
+        self.post_form("/api/user/request-developer", FormFields::new())?;
+
+        Ok(())
+    }
+
+    /// Transfer part of the authenticated user's balance to another user as a gift.
+    ///
+    /// If the balance doesn't cover the amount, this fails with `APIError::InsufficientBalance`
+    /// when the balance is known (from `get_me`), and otherwise with a `BadRequest` whose `code()`
+    /// is `ServerErrorCode::InsufficientBalance`.
+    ///
+    /// # Arguments
+    /// * `recipient_user_id` - The id of the user receiving the funds
+    /// * `amount` - The amount to transfer (must be positive)
+    /// * `idempotency_key` - A key identifying this operation, so that repeating it (e.g. after a
+    ///   network failure) isn't applied twice; a fresh key is generated if `None`
+    pub fn transfer_balance(&self, recipient_user_id: i32, amount: f32,
+                            idempotency_key: Option<String>) -> ApiResult<Transaction> {
+        if !(amount.is_finite() && amount > 0.0) {
+            return Err(APIError::Validation(vec![FieldError {
+                field: String::from("amount"),
+                message: String::from("The amount must be greater than zero.")
+            }]));
+        }
+
+        let headers: HeaderMap = self.get_idempotent_headers(idempotency_key)?;
+        let url: Url = self.get_url_for("/api/user/transfer");
+
+        let form: FormFields = FormFields::new()
+            .text("recipient_user_id", recipient_user_id.to_string())
+            .text("amount", amount.to_string());
+
+        let request: RequestBuilder = self.client
+            .post(url.as_str())
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => {
+                let error: APIError = APIError::BadRequest(self.read_text(response)?);
+
+                match (error.code(), *self.known_balance.read().unwrap()) {
+                    (Some(ServerErrorCode::InsufficientBalance), Some(balance)) =>
+                        Err(APIError::InsufficientBalance { balance, price: amount }),
+                    _ => Err(error)
+                }
+            },
+            StatusCode::OK => {
+                // The balance has changed, so it's no longer known.
+                self.set_known_balance(None);
+
+                // Parse the response.
+                let transaction: Transaction = self.read_json(response)?;
+
+                Ok(transaction)
+            },
+            _ => Err(APIError::UnhandledStatusCode(response.status()))
+        }
+    }
+
+    /// Get a user's balance (without fetching the entire user).
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    pub fn get_balance(&self, user_id: i32) -> ApiResult<f32> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
+
+        let balance_response: GetBalanceResponse = self.get_json("/api/user/balance", form)?;
+
+        Ok(balance_response.balance)
+    }
+
     /// Attempt to authenticate the current session (must have a valid session id).
     pub fn authenticate_session(&self) -> ApiResult<SessionAuthenticationResponse> {
+        let authentication_response: SessionAuthenticationResponse =
+            self.get_json("/api/session/authenticate", FormFields::new())?;
+
+        if let Some(user_id) = authentication_response.user_id {
+            self.set_cache_user(user_id);
+        }
+
+        Ok(authentication_response)
+    }
+
+    /// Start a batch of operations to run in order, stopping at the first failure (e.g.
+    /// `api.batch().then(|api| api.register(...)).then(|api| api.login(...)).execute()`).
+    pub fn batch(&self) -> Batch<'_> {
+        Batch::new(self)
+    }
+
+    /// Open a stream of real-time events (e.g. friend activity and incoming invites) from the
+    /// server. The returned iterator blocks until the next event arrives, and ends when the server
+    /// closes the stream.
+    pub fn stream_events(&self) -> ApiResult<EventStream> {
         let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/session/authenticate");
+        let url: Url = self.get_url_for("/api/events");
+
+        // The stream stays open indefinitely, so it can't share the default client's timeout.
+        let client: Client = Client::builder()
+            .timeout(None)
+            .build()?;
 
-        let response: Response = self.client
+        let response: Response = client
             .get(url.as_str())
             .headers(headers)
+            .header("Accept", "text/event-stream")
             .send()?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // The request is okay; parse the response.
-                let response: SessionAuthenticationResponse =
-                    from_str(&response.text()?)?;
-
-                Ok(response)
-            },
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::OK => Ok(EventStream::new(response)),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
+    /// Connect to the presence websocket, which delivers friend activity and invite events as they
+    /// happen (must have a valid session id).
+    #[cfg(feature = "websocket")]
+    pub fn connect_presence(&self) -> ApiResult<presence::PresenceChannel> {
+        let session_id: String = match self.get_session_id() {
+            Some(session_id) => session_id,
+            None => return Err(APIError::Unauthorized(String::from("No session id.")))
+        };
+
+        let mut url: Url = self.get_url_for("/ws");
+        let scheme: &str = if url.scheme() == "https" { "wss" } else { "ws" };
+        url.set_scheme(scheme).unwrap();
+
+        presence::PresenceChannel::connect(url.as_str(), &session_id)
+    }
+
     /// Attempt to delete the current session (not to be confused with `delete_specific_session`).
-    pub fn delete_session(&self) -> ApiResult<()> {
+    /// Returns whether a session was deleted; a session that's already gone isn't an error, so
+    /// retrying a logout is safe. The offline cache is cleared either way.
+    pub fn delete_session(&self) -> ApiResult<bool> {
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/session/delete");
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .delete(url.as_str())
-            .headers(headers)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(request)?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            // The session was already deleted (e.g. this is a retry).
+            StatusCode::NOT_FOUND => {
+                self.clear_cache();
+
+                Ok(false)
+            },
             StatusCode::OK => {
-                Ok(())
+                self.clear_cache();
+
+                Ok(true)
             },
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
-    pub fn delete_specific_session(&self, session_id: i32) -> ApiResult<()> {
+    /// Delete every session of the authenticated user except the current one ("sign out other
+    /// devices"), returning how many sessions were deleted.
+    pub fn delete_other_sessions(&self) -> ApiResult<u64> {
+        let delete_response: DeleteOtherSessionsResponse =
+            self.read_json(self.delete_form("/api/session/delete-others", FormFields::new())?)?;
+
+        Ok(delete_response.deleted)
+    }
+
+    /// Select the release branch (e.g. "beta") the authenticated user gets for an application.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    /// * `branch` - The release branch
+    pub fn set_user_branch(&self, application_id: i32, branch: String) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string())
+            .text("branch", branch);
+
+        self.put_form("/api/user/branch/set", form)?;
+
+        Ok(())
+    }
+
+    /// Get the release branch the authenticated user has selected for an application
+    /// (`DEFAULT_BRANCH` unless they've opted into another).
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    pub fn get_user_branch(&self, application_id: i32) -> ApiResult<String> {
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string());
+
+        let branch_response: GetUserBranchResponse = self.get_json("/api/user/branch/get", form)?;
+
+        Ok(branch_response.branch)
+    }
+
+    /// Set the version a release branch (e.g. "beta") serves, without changing the application's
+    /// latest (stable) version.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    /// * `branch` - The release branch
+    /// * `version_name` - The version name (e.g. "1.1-beta")
+    pub fn set_branch_version(&self, application_id: i32, branch: String,
+                              version_name: String) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string())
+            .text("branch", branch)
+            .text("version", version_name);
+
+        self.put_form("/api/application/update-branch-version", form)?;
+
+        Ok(())
+    }
+
+    /// Attempt to delete one of the user's sessions. Returns whether a session was deleted; a
+    /// session that's already gone isn't an error.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session's id
+    pub fn delete_specific_session(&self, session_id: i32) -> ApiResult<bool> {
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/session/delete-specific");
 
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("session_id", session_id.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .delete(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                Ok(())
-            },
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            // The session was already deleted (e.g. this is a retry).
+            StatusCode::NOT_FOUND => Ok(false),
+            StatusCode::OK => Ok(true),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
@@ -492,41 +1666,86 @@ impl ApiService {
     /// * `genres` - The list of the application's genres
     /// * `tags` - The list of the application's tags
     /// * `base_price` - The base price of the application
+    ///
+    /// The genres are checked against `get_all_genres` (fetched once, then remembered) before the
+    /// application is created. If any of them are unknown, or the server rejects any of the
+    /// fields, this fails with `APIError::Validation` listing each invalid field (as does a base
+    /// price outside the server's limits, if they're known). If the genres can't be fetched, they
+    /// are left to the server to check. If the package name is already taken, this fails with
+    /// `APIError::DuplicatePackageName`.
+    ///
+    /// The lists are sent as arrays with JSON bodies (see `with_json_bodies`), and comma-separated
+    /// otherwise; in that case, a value containing a comma fails with `APIError::Validation`
+    /// rather than being split.
     pub fn create_application(&self, name: String, package_name: String,
                               application_type: String, description: String,
                               release_date: String, early_access: bool,
                               supported_platforms: Vec<String>, genres: Vec<String>,
                               tags: Vec<String>,
                               base_price: f32) -> ApiResult<ApplicationCreationResponse> {
+        // Make sure the genres are known ones (tags are free-form). If the genres can't be
+        // fetched, the server still validates them.
+        let known_genres: Option<Vec<String>> = self.known_genres();
+        let mut field_errors: Vec<FieldError> = genres.iter()
+            .filter(|genre| known_genres.as_ref().is_some_and(|known| !known.contains(genre)))
+            .map(|genre| FieldError {
+                field: String::from("genres"),
+                message: format!("Unknown genre: {}", genre)
+            })
+            .collect();
+
+        field_errors.extend(self.check_limits(|limits| limits.check_price("base_price",
+                                                                          base_price)));
+        field_errors.extend(self.check_list("supported_platforms", &supported_platforms));
+        field_errors.extend(self.check_list("genres", &genres));
+        field_errors.extend(self.check_list("tags", &tags));
+
+        if !field_errors.is_empty() {
+            return Err(APIError::Validation(field_errors));
+        }
+
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/application/create");
 
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("name", name)
-            .text("package_name", package_name)
+            .text("package_name", package_name.clone())
             .text("type", application_type)
             .text("description", description)
             .text("release_date", release_date)
             .text("early_access", early_access.to_string())
-            .text("supported_platforms", supported_platforms.join(","))
-            .text("genres", genres.join(","))
-            .text("tags", tags.join(","))
+            .list("supported_platforms", &supported_platforms)
+            .list("genres", &genres)
+            .list("tags", &tags)
             .text("base_price", base_price.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => {
+                let text: String = self.read_text(response)?;
+
+                if ServerErrorCode::parse(&text) == Some(ServerErrorCode::PackageNameTaken) {
+                    return Err(APIError::DuplicatePackageName(package_name));
+                }
+
+                // Use the field-level validation errors, if the server sent them.
+                match from_str::<ValidationErrorResponse>(&text) {
+                    Ok(validation_response) => {
+                        Err(APIError::Validation(validation_response.errors))
+                    },
+                    Err(_) => Err(APIError::BadRequest(text))
+                }
+            },
             StatusCode::CREATED => {
                 // Parse the response.
-                let creation_response: ApplicationCreationResponse =
-                    from_str(&response.text()?)?;
+                let creation_response: ApplicationCreationResponse = self.read_json(response)?;
 
                 Ok(creation_response)
             },
@@ -534,70 +1753,184 @@ impl ApiService {
         }
     }
 
+    /// Get the list of known application genres. The list is remembered, so that
+    /// `create_application` can check genres without fetching them again.
+    pub fn get_all_genres(&self) -> ApiResult<Vec<String>> {
+        let genres_response: GetGenresResponse = self.get_json("/api/genres", FormFields::new())?;
+
+        *self.known_genres.write().unwrap() = Some(genres_response.genres.clone());
+
+        Ok(genres_response.genres)
+    }
+
+    /// Get the list of application tags in use.
+    pub fn get_all_tags(&self) -> ApiResult<Vec<String>> {
+        let tags_response: GetTagsResponse = self.get_json("/api/tags", FormFields::new())?;
+
+        Ok(tags_response.tags)
+    }
+
     /// Get an application by its unique id.
     ///
     /// # Arguments
     /// * `application_id` - The application's id
     pub fn get_application(&self, application_id: i32) -> ApiResult<Application> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/application/get");
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string());
 
-        let form: Form = Form::new()
+        self.get_json("/api/application/get", form)
+    }
+
+    /// Get multiple applications by their unique ids.
+    ///
+    /// # Arguments
+    /// * `ids` - The applications' ids
+    pub fn get_applications(&self, ids: Vec<i32>) -> ApiResult<Vec<Application>> {
+        let form: FormFields = FormFields::new()
+            .list("application_ids", &ids);
+
+        let applications_response: GetApplicationsResponse =
+            self.get_json("/api/application/get-many", form)?;
+
+        Ok(applications_response.applications)
+    }
+
+    /// Get the currently trending applications, most popular first.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum number of applications to return
+    pub fn get_trending_applications(&self, limit: u32) -> ApiResult<Vec<Application>> {
+        let form: FormFields = FormFields::new()
+            .text("limit", limit.to_string());
+
+        let applications_response: GetApplicationsResponse =
+            self.get_json("/api/application/trending", form)?;
+
+        Ok(applications_response.applications)
+    }
+
+    /// Get the most recently released applications, newest first.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum number of applications to return
+    pub fn get_new_releases(&self, limit: u32) -> ApiResult<Vec<Application>> {
+        let form: FormFields = FormFields::new()
+            .text("limit", limit.to_string());
+
+        let applications_response: GetApplicationsResponse =
+            self.get_json("/api/application/new", form)?;
+
+        Ok(applications_response.applications)
+    }
+
+    /// Get an application's sales figures for a period.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    /// * `start` - The start date of the period
+    /// * `end` - The end date of the period
+    pub fn get_application_revenue(&self, application_id: i32, start: String,
+                                   end: String) -> ApiResult<RevenueReport> {
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string())
+            .text("start", start)
+            .text("end", end);
+
+        self.get_json("/api/application/revenue", form)
+    }
+
+    /// Get how long a user has spent in an application in total, in seconds (the sum of the
+    /// lengths of their application sessions, computed by the server).
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    pub fn get_total_playtime(&self, user_id: i32, application_id: i32) -> ApiResult<i64> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string())
             .text("application_id", application_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        let playtime_response: PlaytimeResponse =
+            self.get_json("/api/application/playtime", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // Parse the response.
-                let application: Application = from_str(&response.text()?)?;
+        Ok(playtime_response.total_seconds)
+    }
 
-                Ok(application)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+    /// Get the applications published by a developer.
+    ///
+    /// # Arguments
+    /// * `user_id` - The developer's user id
+    pub fn get_developer_applications(&self, user_id: i32) -> ApiResult<Vec<Application>> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
+
+        let applications_response: GetApplicationsResponse =
+            self.get_json("/api/application/by-developer", form)?;
+
+        Ok(applications_response.applications)
+    }
+
+    /// Export a developer's applications as definitions, which can be written to a catalog
+    /// manifest and re-created elsewhere (e.g. promoting a staging catalog to production).
+    ///
+    /// # Arguments
+    /// * `user_id` - The developer's user id
+    pub fn export_catalog(&self, user_id: i32) -> ApiResult<Vec<ApplicationDefinition>> {
+        let applications: Vec<Application> = self.get_developer_applications(user_id)?;
+
+        Ok(applications.into_iter().map(ApplicationDefinition::from).collect())
+    }
+
+    /// Like `get_application`, but serves the cached application (flagged as offline) if the
+    /// server can't be reached. Requires offline mode to be enabled (see `with_offline_mode`).
+    pub fn get_application_offline(&self,
+                                   application_id: i32) -> ApiResult<CachedResponse<Application>> {
+        let key: String = format!("application:{}", application_id);
+
+        self.with_offline_fallback(key, || self.get_application(application_id))
     }
 
-    /// Get all the versions for a specific application and platform.
+    /// Get all the versions for a specific application and platform (for the current machine's
+    /// architecture).
     ///
     /// # Arguments
     /// * `application_id` - The application's id
     /// * `platform` - The target platform
     pub fn get_application_versions(&self, application_id: i32,
                                     platform: String) -> ApiResult<Vec<ApplicationVersion>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/application/versions");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string())
-            .text("platform", platform);
+            .text("platform", platform)
+            .text("architecture", self.get_architecture());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        let api_response: GetApplicationVersionsResponse =
+            self.get_json("/api/application/versions", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // Parse the response.
-                let api_response: GetApplicationVersionsResponse =
-                    from_str(&response.text()?)?;
+        Ok(api_response.versions)
+    }
 
-                Ok(api_response.versions)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
+    /// Get the platforms an application has at least one version for (e.g. for platform badges
+    /// on a store page), without fetching the versions themselves.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    pub fn get_available_platforms(&self, application_id: i32) -> ApiResult<Vec<Platform>> {
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string());
+
+        let platforms_response: GetAvailablePlatformsResponse =
+            self.get_json("/api/application/platforms", form)?;
+
+        // Each platform is only listed once.
+        let mut platforms: Vec<Platform> = Vec::new();
+
+        for platform in platforms_response.platforms {
+            if !platforms.contains(&platform) {
+                platforms.push(platform);
+            }
         }
+
+        Ok(platforms)
     }
 
     /// Get a specific application version by its unique id.
@@ -605,30 +1938,10 @@ impl ApiService {
     /// # Arguments
     /// * `version_id` - The version's id
     pub fn get_application_version(&self, version_id: i32) -> ApiResult<ApplicationVersion> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/application/versions/get-specific");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("version_id", version_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // Parse the response.
-                let application_version: ApplicationVersion = from_str(&response.text()?)?;
-
-                Ok(application_version)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.get_json("/api/application/versions/get-specific", form)
     }
 
     /// Get a specific application version by its platform and version name.
@@ -637,80 +1950,190 @@ impl ApiService {
     /// * `application_id` - The application's id
     /// * `platform` - The target platform
     /// * `version_name` - The target version name (e.g. "1.0")
+    /// * `architecture` - The target architecture (defaults to the current machine's)
+    /// * `branch` - The release branch (defaults to the user's selected branch, see
+    ///   `set_user_branch`)
     pub fn get_application_version_for(
             &self, application_id: i32,
-            version_name: String, platform: String) -> ApiResult<ApplicationVersion> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/application/versions/get/fine-tuned");
-
-        let form: Form = Form::new()
+            version_name: String, platform: String, architecture: Option<String>,
+            branch: Option<String>) -> ApiResult<ApplicationVersion> {
+        let mut form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string())
             .text("version_name", version_name)
-            .text("platform", platform);
+            .text("platform", platform)
+            .text("architecture", architecture.unwrap_or_else(|| self.get_architecture()));
+
+        // Without a branch, the server uses the user's selected branch.
+        if let Some(branch) = branch {
+            form = form.text("branch", branch);
+        }
+
+        self.get_json("/api/application/versions/get/fine-tuned", form)
+    }
+
+    /// Attempt to download a specific application version, returning the (absolute) path of the
+    /// downloaded file. A version whose filename isn't a plain file name (e.g. it contains `..` or
+    /// a path separator) is rejected with `APIError::IOError`.
+    ///
+    /// # Arguments
+    /// * `version_id` - The id of the version you are trying to download
+    /// * `download_folder` - The folder to download the file to (created if it doesn't exist)
+    /// * `collision_policy` - Whether to overwrite or keep an existing file with the same name
+    pub fn download_application_version(&self, version_id: i32, download_folder: String,
+                                        collision_policy: CollisionPolicy) -> ApiResult<PathBuf> {
+        // Get the version.
+        let version: ApplicationVersion = self.get_application_version(version_id.clone())?;
+
+        // Calculate the download filepath (before downloading, so an invalid filename is
+        // rejected straight away).
+        let filepath: PathBuf = resolve_download_path(
+            Path::new(&download_folder),
+            &version.filename,
+            collision_policy
+        )?;
+
+        // Download the version.
+        let file_contents: Bytes = self.fetch_application_version_bytes(version_id)?;
+
+        // Write the file contents to a temporary file, which is removed if the write fails, and
+        // then move it into place.
+        let mut partial_filepath: OsString = filepath.clone().into_os_string();
+        partial_filepath.push(".part");
+
+        let (partial_file, mut file) = PartialFile::create(PathBuf::from(partial_filepath))?;
+
+        file.write_all(&file_contents)?;
+        drop(file);
+
+        partial_file.persist(&filepath)?;
+
+        Ok(filepath)
+    }
+
+    /// Fetch the contents of an application version without writing them to disk (e.g. for
+    /// in-memory patching or verification).
+    ///
+    /// The whole file is held in memory, so for large versions prefer
+    /// `install_application_version`, which streams the download to disk.
+    ///
+    /// # Arguments
+    /// * `version_id` - The id of the version to fetch
+    pub fn fetch_application_version_bytes(&self, version_id: i32) -> ApiResult<Bytes> {
+        let headers: HeaderMap = self.get_headers();
+        let url: Url = self.get_url_for("/api/application/versions/download");
+
+        let form: FormFields = FormFields::new()
+            .text("version_id", version_id.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // Parse the response.
-                let application_version: ApplicationVersion = from_str(&response.text()?)?;
+        let response: Response = self.send(self.with_fields(request, form))?;
 
-                Ok(application_version)
-            },
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::OK => Ok(response.bytes()?),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
-    /// Attempt to download a specific application version.
+    /// Download and install an application version into a folder, reporting progress through a
+    /// callback. The download is written to a temporary file and only moved into place once its
+    /// size has been verified; if the download fails, the temporary file is removed. A version
+    /// whose filename isn't a plain file name (e.g. it contains `..` or a path separator) is
+    /// rejected with `APIError::IOError` before anything is downloaded.
+    ///
+    /// The callback receives download progress, then `Verifying`, and finally `Done`.
     ///
     /// # Arguments
-    /// * `version_id` - The id of the version you are trying to download
-    /// * `download_folder` - The folder to download the file to
-    pub fn download_application_version(&self, version_id: i32,
-                                        download_folder: String) -> ApiResult<()> {
+    /// * `version_id` - The id of the version to install
+    /// * `install_folder` - The folder to install the version to (created if it doesn't exist)
+    /// * `on_event` - Called with each progress event
+    pub fn install_application_version<F>(&self, version_id: i32, install_folder: PathBuf,
+                                          mut on_event: F) -> ApiResult<PathBuf>
+    where
+        F: FnMut(InstallEvent)
+    {
         // Get the version.
-        let version: ApplicationVersion = self.get_application_version(version_id.clone())?;
+        let version: ApplicationVersion = self.get_application_version(version_id)?;
+
+        // The filename comes from the server, so make sure it can't escape the install folder.
+        if !is_safe_filename(&version.filename) {
+            return Err(APIError::IOError(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid version filename: {:?}", version.filename)
+            )));
+        }
 
         // Send the version download request.
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/application/versions/download");
 
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("version_id", version_id.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let mut response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
             StatusCode::OK => {
-                // The server is okay with the file download; parse the response.
-                // Calculate the download filepath.
-                let mut filepath: PathBuf = PathBuf::from(download_folder);
-                filepath.push(version.filename);
+                create_dir_all(&install_folder)?;
 
-                // Create the file.
-                let mut file: File = File::create(filepath)?;
+                let filepath: PathBuf = install_folder.join(&version.filename);
+                let partial_filepath: PathBuf =
+                    install_folder.join(format!("{}.part", version.filename));
 
-                // Get the response bytes.
-                let file_contents: Bytes = response.bytes()?;
+                // Stream the download to the temporary file, reporting progress as it goes. The
+                // total is unknown for chunked downloads (which don't send a content length). The
+                // temporary file is removed if anything fails before it's moved into place.
+                let total: Option<u64> = response.content_length();
+                let mut done: u64 = 0;
+                let (partial_file, mut file) = PartialFile::create(partial_filepath)?;
+                let mut buffer: Vec<u8> = vec![0; 64 * 1024];
 
-                // Write the file contents.
-                file.write_all(&file_contents).expect("Failed to write file contents.");
+                on_event(InstallEvent::DownloadProgress { done, total });
 
-                Ok(())
+                loop {
+                    let n: usize = response.read(&mut buffer)?;
+
+                    if n == 0 {
+                        break;
+                    }
+
+                    file.write_all(&buffer[..n])?;
+                    done += n as u64;
+
+                    on_event(InstallEvent::DownloadProgress { done, total });
+                }
+
+                file.flush()?;
+                drop(file);
+
+                // Make sure the whole file arrived before installing it (chunked downloads are
+                // complete once the final chunk has been read).
+                on_event(InstallEvent::Verifying);
+
+                if total.is_some_and(|total| total != done) {
+                    return Err(APIError::IOError(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        format!("Downloaded {} of {:?} bytes.", done, total)
+                    )));
+                }
+
+                partial_file.persist(&filepath)?;
+
+                on_event(InstallEvent::Done);
+
+                Ok(filepath)
             },
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
@@ -719,56 +2142,68 @@ impl ApiService {
     /// Attempt to update the specified application's latest version.
     pub fn update_application_version(&self, application_id: i32,
                                       version_name: String) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/application/update-version");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string())
             .text("version", version_name.to_string());
 
-        let response: Response = self.client
-            .put(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                Ok(())
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.put_form("/api/application/update-version", form)?;
+
+        Ok(())
+    }
+
+    /// Get a version's changelog (patch notes), e.g. for an update prompt.
+    ///
+    /// # Arguments
+    /// * `version_id` - The version's id
+    pub fn get_version_changelog(&self, version_id: i32) -> ApiResult<Option<String>> {
+        let version: ApplicationVersion = self.get_application_version(version_id)?;
+
+        Ok(version.changelog)
     }
 
-    pub fn create_application_version(&self, application_id: i32, name: String,
-                                      platform: String, release_date: String,
-                                      filename: String, executable: String,
-                                      filepath: String) -> ApiResult<()> {
+    /// Upload a new application version.
+    ///
+    /// # Arguments
+    /// * `version` - The version (see `NewApplicationVersion` for the optional branch and
+    ///   changelog)
+    pub fn create_application_version(&self, version: NewApplicationVersion) -> ApiResult<()> {
+        let size_error: Option<FieldError> = self.check_limits(|limits| {
+            limits.check_upload_size("file", metadata(&version.filepath).ok()?.len())
+        });
+
+        if let Some(size_error) = size_error {
+            return Err(APIError::Validation(vec![size_error]));
+        }
+
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/version/create");
 
-        let form: Form = Form::new()
-            .text("application_id", application_id.to_string())
-            .text("name", name)
-            .text("platform", platform)
-            .text("release_date", release_date)
-            .text("filename", filename)
-            .text("executable", executable)
-            .file("file", filepath)?;
+        let mut form: Form = Form::new()
+            .text("application_id", version.application_id.to_string())
+            .text("name", version.name)
+            .text("platform", version.platform)
+            .text("release_date", version.release_date)
+            .text("filename", version.filename)
+            .text("executable", version.executable)
+            .text("branch", self.get_branch(version.branch));
+
+        if let Some(changelog) = version.changelog {
+            form = form.text("changelog", changelog);
+        }
+
+        let form: Form = form.file("file", version.filepath)?;
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .post(url.as_str())
             .headers(headers)
-            .multipart(form)
-            .send()?;
+            .multipart(form);
+
+        let response: Response = self.send(request)?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
             StatusCode::OK => {
                 Ok(())
             },
@@ -779,10 +2214,11 @@ impl ApiService {
     pub fn create_sale(&self, application_id: i32, title: String, description: String,
                        price: f32, start_date: String,
                        end_date: String) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/sales/create");
+        if let Some(price_error) = self.check_limits(|limits| limits.check_price("price", price)) {
+            return Err(APIError::Validation(vec![price_error]));
+        }
 
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string())
             .text("title", title)
             .text("description", description)
@@ -790,301 +2226,339 @@ impl ApiService {
             .text("start_date", start_date)
             .text("end_date", end_date);
 
-        let response: Response = self.client
-            .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.post_form("/api/sales/create", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                Ok(())
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(())
     }
 
     pub fn get_active_sale(&self, application_id: i32) -> ApiResult<Sale> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/sales/get");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.get_json("/api/sales/get", form)
+    }
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let sale: Sale = from_str(&response.text()?)?;
+    pub fn get_all_sales(&self) -> ApiResult<Vec<Sale>> {
+        let sales_response: GetAllSalesResponse =
+            self.get_json("/api/sales/get-all", FormFields::new())?;
 
-                Ok(sale)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(sales_response.sales)
     }
 
-    pub fn get_all_sales(&self) -> ApiResult<Vec<Sale>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/sales/get-all");
+    /// Get the sales of all of a developer's applications.
+    ///
+    /// # Arguments
+    /// * `user_id` - The developer's user id
+    pub fn get_developer_sales(&self, user_id: i32) -> ApiResult<Vec<Sale>> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .send()?;
+        let sales_response: GetAllSalesResponse = self.get_json("/api/sales/by-developer", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // Parse the response.
-                let sales_response: GetAllSalesResponse = from_str(&response.text()?)?;
+        Ok(sales_response.sales)
+    }
 
-                Ok(sales_response.sales)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+    /// Get the applications that are currently on sale, each paired with its active sale.
+    pub fn get_applications_on_sale(&self) -> ApiResult<Vec<(Application, Sale)>> {
+        let sales_response: GetActiveSalesResponse =
+            self.get_json("/api/sales/active", FormFields::new())?;
+
+        Ok(sales_response.sales
+            .into_iter()
+            .map(|active_sale| (active_sale.application, active_sale.sale))
+            .collect())
     }
 
     pub fn delete_sale(&self, sale_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/sales/delete");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("sale_id", sale_id.to_string());
 
-        let response: Response = self.client
-            .delete(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.delete_form("/api/sales/delete", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(())
     }
 
     pub fn get_user_transactions(&self, user_id: i32) -> ApiResult<Vec<Transaction>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-transactions");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // Parse the response.
-                let transactions_response: GetUserTransactionsResponse =
-                    from_str(&response.text()?)?;
+        let transactions_response: GetUserTransactionsResponse =
+            self.get_json("/api/user/get-transactions", form)?;
 
-                Ok(transactions_response.transactions)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(transactions_response.transactions)
     }
     pub fn get_transaction(&self, transaction_id: i32) -> ApiResult<Transaction> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-transaction");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("transaction_id", transaction_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.get_json("/api/user/get-transaction", form)
+    }
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let transaction: Transaction = from_str(&response.text()?)?;
+    pub fn get_purchase(&self, purchase_id: i32) -> ApiResult<Purchase> {
+        let form: FormFields = FormFields::new()
+            .text("purchase_id", purchase_id.to_string());
 
-                Ok(transaction)
+        self.get_json("/api/user/get-purchase", form)
+    }
+
+    /// Put together a receipt for a purchase, with the name of what was bought and who bought it.
+    /// Tax isn't itemized by the server, so the receipt's `tax` is `None`.
+    ///
+    /// # Arguments
+    /// * `purchase_id` - The purchase's id
+    pub fn get_purchase_receipt(&self, purchase_id: i32) -> ApiResult<Receipt> {
+        let purchase: Purchase = self.get_purchase(purchase_id)?;
+
+        let item: String = match purchase.kind() {
+            PurchaseKind::Application(application_id) => {
+                self.get_application(application_id)?.name
             },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+            PurchaseKind::Iap(iap_id) => self.get_iap(iap_id)?.title
+        };
+
+        let buyer: PublicProfile = self.get_public_profile(purchase.user_id)?;
+
+        Ok(Receipt {
+            purchase_id: purchase.id,
+            kind: purchase.kind(),
+            item,
+            price: purchase.price,
+            tax: None,
+            date: purchase.date,
+            key: purchase.key,
+            buyer: format!("{} ({})", buyer.name, buyer.username)
+        })
     }
 
-    pub fn get_purchase(&self, purchase_id: i32) -> ApiResult<Purchase> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-purchase");
+    pub fn get_user_purchases(&self, user_id: i32) -> ApiResult<Vec<Purchase>> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
 
-        let form: Form = Form::new()
-            .text("purchase_id", purchase_id.to_string());
+        let purchases_response: GetUserPurchasesResponse =
+            self.get_json("/api/user/get-purchases", form)?;
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        Ok(purchases_response.purchases)
+    }
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let purchase: Purchase = from_str(&response.text()?)?;
+    /// Like `get_user_purchases`, but serves the cached purchases (flagged as offline) if the
+    /// server can't be reached, so the user's library is still available. Requires offline mode
+    /// to be enabled (see `with_offline_mode`).
+    pub fn get_user_purchases_offline(&self,
+                                      user_id: i32) -> ApiResult<CachedResponse<Vec<Purchase>>> {
+        let key: String = format!("purchases:{}", user_id);
 
-                Ok(purchase)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.with_offline_fallback(key, || self.get_user_purchases(user_id))
     }
 
     pub fn get_deposit(&self, deposit_id: i32) -> ApiResult<Deposit> {
+        let form: FormFields = FormFields::new()
+            .text("deposit_id", deposit_id.to_string());
+
+        self.get_json("/api/user/get-deposit", form)
+    }
+
+    pub fn get_application_key(&self, key: String) -> ApiResult<ApplicationKey> {
+        let form: FormFields = FormFields::new()
+            .text("key", key.to_string());
+
+        self.get_json("/api/user/get-application-key", form)
+    }
+
+    /// Look up an application key together with the application it unlocks (e.g. to show what a
+    /// key gives before redeeming it).
+    ///
+    /// # Arguments
+    /// * `key` - The application key
+    pub fn resolve_application_key(&self, key: String) -> ApiResult<(ApplicationKey, Application)> {
+        let application_key: ApplicationKey = self.get_application_key(key)?;
+        let application: Application = self.get_application(application_key.application_id)?;
+
+        Ok((application_key, application))
+    }
+
+    pub fn get_user_application_keys(&self, user_id: i32) -> ApiResult<Vec<ApplicationKey>> {
         let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-deposit");
+        let url: Url = self.get_url_for("/api/user/get-application-keys");
 
-        let form: Form = Form::new()
-            .text("deposit_id", deposit_id.to_string());
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
             StatusCode::OK => {
-                let deposit: Deposit = from_str(&response.text()?)?;
+                let keys_response: GetUserApplicationKeysResponse = self.read_json(response)?;
 
-                Ok(deposit)
+                Ok(keys_response.application_keys)
             },
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
-    pub fn get_application_key(&self, key: String) -> ApiResult<ApplicationKey> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-application-key");
+    /// Revoke a specific application key so it can no longer be redeemed.
+    ///
+    /// # Arguments
+    /// * `key` - The application key to revoke
+    pub fn revoke_application_key(&self, key: String) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("key", key);
+
+        self.post_form("/api/key/revoke", form)?;
+
+        Ok(())
+    }
+
+    /// Revoke all the unredeemed keys for an application, returning the number of keys revoked.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    pub fn revoke_application_keys(&self, application_id: i32) -> ApiResult<u64> {
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string());
 
-        let form: Form = Form::new()
-            .text("key", key.to_string());
+        let revoke_response: RevokeApplicationKeysResponse =
+            self.read_json(self.post_form("/api/key/revoke", form)?)?;
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        Ok(revoke_response.revoked)
+    }
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let application_key: ApplicationKey = from_str(&response.text()?)?;
+    /// Extend a time-limited application key's validity (e.g. renewing a rental), returning the
+    /// updated key.
+    ///
+    /// # Arguments
+    /// * `key` - The application key to extend
+    /// * `days` - The number of days to extend the key by
+    pub fn extend_application_key(&self, key: String, days: u32) -> ApiResult<ApplicationKey> {
+        let form: FormFields = FormFields::new()
+            .text("key", key)
+            .text("days", days.to_string());
 
-                Ok(application_key)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.read_json(self.post_form("/api/key/extend", form)?)
     }
 
-    pub fn get_user_application_keys(&self, user_id: i32) -> ApiResult<Vec<ApplicationKey>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-application-keys");
+    /// Purchase an application.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    /// * `idempotency_key` - A key identifying this operation, so that repeating it (e.g. after a
+    ///   network failure) isn't applied twice; a fresh key is generated if `None`
+    pub fn purchase_application(&self, application_id: i32,
+                                idempotency_key: Option<String>) -> ApiResult<()> {
+        let headers: HeaderMap = self.get_idempotent_headers(idempotency_key)?;
+        let url: Url = self.get_url_for("/api/purchase/application");
 
-        let form: Form = Form::new()
-            .text("user_id", user_id.to_string());
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        let request: RequestBuilder = self.client
+            .post(url.as_str())
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
             StatusCode::OK => {
-                let keys_response: GetUserApplicationKeysResponse = from_str(&response.text()?)?;
+                // The balance has changed, so it's no longer known.
+                self.set_known_balance(None);
 
-                Ok(keys_response.application_keys)
+                Ok(())
             },
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
-    pub fn purchase_application(&self, application_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/purchase/application");
-
-        let form: Form = Form::new()
+    /// Pre-order an unreleased application. Payment is taken now, and ownership is granted once
+    /// the application is released.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    /// * `idempotency_key` - A key identifying this operation, so that repeating it (e.g. after a
+    ///   network failure) isn't applied twice; a fresh key is generated if `None`
+    pub fn preorder_application(&self, application_id: i32,
+                                idempotency_key: Option<String>) -> ApiResult<()> {
+        let headers: HeaderMap = self.get_idempotent_headers(idempotency_key)?;
+        let url: Url = self.get_url_for("/api/purchase/preorder");
+
+        let form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::OK => {
+                // The balance has changed, so it's no longer known.
+                self.set_known_balance(None);
+
+                Ok(())
+            },
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
-    pub fn purchase_iap(&self, iap_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
+    /// Purchase an in-app purchase.
+    ///
+    /// # Arguments
+    /// * `iap_id` - The in-app purchase's id
+    /// * `idempotency_key` - A key identifying this operation, so that repeating it (e.g. after a
+    ///   network failure) isn't applied twice; a fresh key is generated if `None`
+    pub fn purchase_iap(&self, iap_id: i32, idempotency_key: Option<String>) -> ApiResult<()> {
+        let known_balance: Option<f32> = *self.known_balance.read().unwrap();
+
+        // Reject purchases the user obviously can't afford without asking the server.
+        if let (true, Some(balance)) = (self.balance_precheck, known_balance) {
+            let iap: IAP = self.get_iap(iap_id)?;
+
+            if iap.price > balance {
+                return Err(APIError::InsufficientBalance { balance, price: iap.price });
+            }
+        }
+
+        let headers: HeaderMap = self.get_idempotent_headers(idempotency_key)?;
         let url: Url = self.get_url_for("/api/purchase/iap");
 
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("iap_id", iap_id.to_string());
 
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
 
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::OK => {
+                // The balance has changed, so it's no longer known.
+                self.set_known_balance(None);
+
+                Ok(())
+            },
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
 
     pub fn get_iap_records(&self, user_id: i32, application_id: i32, 
                            only_unacknowledged: bool) -> ApiResult<Vec<IAPRecord>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-iap-records");
-
-        let mut form: Form = Form::new()
+        let mut form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string())
             .text("application_id", application_id.to_string());
 
@@ -1092,315 +2566,246 @@ impl ApiService {
             form = form.text("only_unacknowledged", "true");
         }
         
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let records_response: GetIAPRecordsResponse = from_str(&response.text()?)?;
+        let records_response: GetIAPRecordsResponse =
+            self.get_json("/api/user/get-iap-records", form)?;
 
-                Ok(records_response.iap_records)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(records_response.iap_records)
     }
 
-    pub fn get_session(&self, session_id: String) -> ApiResult<Session> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/session/get");
+    /// Count a user's unacknowledged IAP records for an application (purchases that still have to
+    /// be delivered), without fetching the records themselves. Cheap enough to poll.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    pub fn count_unacknowledged_iap_records(&self, user_id: i32,
+                                            application_id: i32) -> ApiResult<u64> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string())
+            .text("application_id", application_id.to_string());
 
-        let form: Form = Form::new()
-            .text("session_id", session_id.to_string());
+        let count_response: CountIAPRecordsResponse =
+            self.get_json("/api/user/count-unacknowledged-iap-records", form)?;
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        Ok(count_response.count)
+    }
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let session_response: Session = from_str(&response.text()?)?;
+    pub fn get_session(&self, session_id: String) -> ApiResult<Session> {
+        let form: FormFields = FormFields::new()
+            .text("session_id", session_id.to_string());
 
-                Ok(session_response)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.get_json("/api/session/get", form)
     }
 
     pub fn send_friend_request(&self, user_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/friend/send-request");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
-            .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.post_form("/api/friend/send-request", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(())
     }
 
     pub fn delete_friend_request(&self, request_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/friend/delete-request");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("request_id", request_id.to_string());
 
-        let response: Response = self.client
-            .delete(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.delete_form("/api/friend/delete-request", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(())
     }
 
     pub fn get_incoming_friend_requests(&self, user_id: i32) -> ApiResult<Vec<FriendRequest>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/friend/get-requests/incoming");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let friend_requests: GetFriendRequestsResponse = from_str(&response.text()?)?;
+        let friend_requests: GetFriendRequestsResponse =
+            self.get_json("/api/friend/get-requests/incoming", form)?;
 
-                Ok(friend_requests.friend_requests)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(friend_requests.friend_requests)
     }
 
     pub fn get_outgoing_friend_requests(&self, user_id: i32) -> ApiResult<Vec<FriendRequest>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/friend/get-requests/outgoing");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let friend_requests: GetFriendRequestsResponse = from_str(&response.text()?)?;
+        let friend_requests: GetFriendRequestsResponse =
+            self.get_json("/api/friend/get-requests/outgoing", form)?;
 
-                Ok(friend_requests.friend_requests)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(friend_requests.friend_requests)
     }
 
     pub fn accept_friend_request(&self, request_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/friend/accept-request");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("request_id", request_id.to_string());
 
-        let response: Response = self.client
-            .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.post_form("/api/friend/accept-request", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(())
     }
 
     pub fn get_friends(&self, user_id: i32) -> ApiResult<Vec<Friend>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-friends");
-
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        let friends_response: GetFriendsResponse = self.get_json("/api/user/get-friends", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                // Parse the response.
-                let friends_response: GetFriendsResponse = from_str(&response.text()?)?;
+        Ok(friends_response.friends)
+    }
 
-                Ok(friends_response.friends)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
+    /// Fetch a user's friends along with their public profiles, ordered online-first (friends in
+    /// an application, then other online friends, then offline friends).
+    ///
+    /// There's no endpoint for fetching several profiles at once, so each friend's profile is
+    /// fetched separately. Friends whose profile no longer exists are left out rather than failing
+    /// the whole list; any other error (e.g. an expired session) is returned.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `online_only` - Whether to leave out offline friends
+    pub fn get_friends_by_presence(&self, user_id: i32,
+                                   online_only: bool) -> ApiResult<Vec<FriendPresence>> {
+        let mut presences: Vec<FriendPresence> = Vec::new();
+
+        for friend in self.get_friends(user_id)? {
+            // A friendship can be recorded from either side.
+            let friend_user_id: i32 = if friend.user_id == user_id {
+                friend.other_user_id
+            } else {
+                friend.user_id
+            };
+
+            // A friend whose profile is gone (e.g. a deleted account) is skipped.
+            let profile: PublicProfile = match self.get_public_profile(friend_user_id) {
+                Ok(profile) => profile,
+                Err(APIError::NotFound(_)) => continue,
+                Err(APIError::UnhandledStatusCode(StatusCode::NOT_FOUND)) => continue,
+                Err(err) => return Err(err)
+            };
+
+            if online_only && !profile.activity.is_online() {
+                continue;
+            }
+
+            presences.push(FriendPresence { friend, profile });
         }
+
+        presences.sort_by(FriendPresence::online_first);
+
+        Ok(presences)
     }
 
     pub fn remove_friend(&self, user_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/friend/remove");
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string());
 
-        let form: Form = Form::new()
+        self.delete_form("/api/friend/remove", form)?;
+
+        Ok(())
+    }
+
+    /// Follow a developer, so their new releases show up in the authenticated user's
+    /// notifications.
+    ///
+    /// # Arguments
+    /// * `developer_user_id` - The developer's user id
+    pub fn follow_developer(&self, developer_user_id: i32) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("developer_user_id", developer_user_id.to_string());
+
+        self.post_form("/api/follow/add", form)?;
+
+        Ok(())
+    }
+
+    /// Stop following a developer.
+    ///
+    /// # Arguments
+    /// * `developer_user_id` - The developer's user id
+    pub fn unfollow_developer(&self, developer_user_id: i32) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("developer_user_id", developer_user_id.to_string());
+
+        self.delete_form("/api/follow/remove", form)?;
+
+        Ok(())
+    }
+
+    /// Get the public profiles of the developers a user follows.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    pub fn get_followed_developers(&self, user_id: i32) -> ApiResult<Vec<PublicProfile>> {
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
 
-        let response: Response = self.client
-            .delete(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        let followed_response: GetFollowedDevelopersResponse =
+            self.get_json("/api/follow/get-list", form)?;
 
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        Ok(followed_response.developers)
     }
     
     pub fn send_invite(&self, user_id: i32, application_id: i32, 
                        details: String) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/send-invite");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string())
             .text("application_id", application_id.to_string())
             .text("details", details.to_string());
         
-        let response: Response = self.client
-            .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.post_form("/api/user/send-invite", form)?;
+
+        Ok(())
     }
     
     pub fn get_invites(&self, user_id: i32) -> ApiResult<Vec<Invite>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-invites");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
         
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() { 
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let invites_response: GetInvitesResponse = from_str(&response.text()?)?;
-                
-                Ok(invites_response.invites)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        let invites_response: GetInvitesResponse = self.get_json("/api/user/get-invites", form)?;
+
+        Ok(invites_response.invites)
     }
     
     pub fn get_invite(&self, invite_id: i32) -> ApiResult<Invite> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-invite");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("invite_id", invite_id.to_string());
         
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let invite: Invite = from_str(&response.text()?)?;
-                
-                Ok(invite)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.get_json("/api/user/get-invite", form)
     }
     
-    pub fn delete_invite(&self, invite_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/delete-invite");
+    /// Accept an invite, returning it with the details needed to join the inviter (e.g. a join
+    /// token in `details`).
+    ///
+    /// # Arguments
+    /// * `invite_id` - The invite's id
+    pub fn accept_invite(&self, invite_id: i32) -> ApiResult<Invite> {
+        let form: FormFields = FormFields::new()
+            .text("invite_id", invite_id.to_string());
         
-        let form: Form = Form::new()
+        self.read_json(self.post_form("/api/user/accept-invite", form)?)
+    }
+    
+    /// Decline an invite, letting the inviter know (unlike `delete_invite`, which just dismisses
+    /// it).
+    ///
+    /// # Arguments
+    /// * `invite_id` - The invite's id
+    pub fn decline_invite(&self, invite_id: i32) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
             .text("invite_id", invite_id.to_string());
         
-        let response: Response = self.client
-            .delete(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        self.post_form("/api/user/decline-invite", form)?;
+
+        Ok(())
+    }
+    
+    pub fn delete_invite(&self, invite_id: i32) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("invite_id", invite_id.to_string());
         
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.delete_form("/api/user/delete-invite", form)?;
+
+        Ok(())
     }
     
     pub fn create_photo(&self, subfolder: String, filepath: String) -> ApiResult<()> {
@@ -1411,329 +2816,415 @@ impl ApiService {
             .text("subfolder", subfolder.to_string())
             .file("photo", filepath)?;
         
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .post(url.as_str())
             .headers(headers)
-            .multipart(form)
-            .send()?;
+            .multipart(form);
+
+        let response: Response = self.send(request)?;
         
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
             StatusCode::OK => Ok(()),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
     
     pub fn get_photo(&self, id: i32) -> ApiResult<Value> {
+        // Get the photo's bytes.
+        let response_bytes: Bytes = self.get_photo_bytes(id)?;
+
+        // Encode the bytes into base 64.
+        let base64: String = BASE64_STANDARD.encode(response_bytes);
+
+        Ok(json!({
+            "bytes": base64
+        }))
+    }
+
+    /// Download a photo's raw bytes.
+    ///
+    /// # Arguments
+    /// * `id` - The photo's id
+    pub fn get_photo_bytes(&self, id: i32) -> ApiResult<Bytes> {
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/photo/get");
         
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("id", id.to_string());
         
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
         
         match &response.status() {
-            &StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            &StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            &StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            &StatusCode::OK => {
-                // Get the photo's bytes.
-                let response_bytes: Bytes = response.bytes()?;
-
-                // Encode the bytes into base 64.
-                let base64: String = BASE64_STANDARD.encode(response_bytes);
-                
-                Ok(json!({
-                    "bytes": base64
-                }))
-            },
+            &StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            &StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            &StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            &StatusCode::OK => Ok(response.bytes()?),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
     
-    pub fn create_iap(&self, application_id: i32, title: String, description: String, 
-                      price: f32, data: String) -> ApiResult<()> {
+    /// Download a user's profile photo into the specified cache folder, returning its path.
+    /// Photos are stored by id, so an unchanged avatar is only downloaded once.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `dest` - The folder to cache profile photos in (created if it doesn't exist)
+    pub fn get_profile_photo(&self, user_id: i32, dest: PathBuf) -> ApiResult<PathBuf> {
+        let profile: PublicProfile = self.get_public_profile(user_id)?;
+
+        let photo_id: i32 = profile.profile_photo_id.ok_or_else(|| {
+            APIError::NotFound(format!("User {} doesn't have a profile photo.", user_id))
+        })?;
+
+        let filepath: PathBuf = dest.join(format!("profile-photo-{}", photo_id));
+
+        // The photo has already been downloaded.
+        if filepath.exists() {
+            return Ok(filepath);
+        }
+
+        let photo_bytes: Bytes = self.get_photo_bytes(photo_id)?;
+
+        // Write to a temporary file first (removed if the write fails), so an interrupted
+        // download is never served from the cache.
+        create_dir_all(&dest)?;
+
+        let partial_filepath: PathBuf = dest.join(format!("profile-photo-{}.part", photo_id));
+        let (partial_file, mut file) = PartialFile::create(partial_filepath)?;
+
+        file.write_all(&photo_bytes)?;
+        drop(file);
+
+        partial_file.persist(&filepath)?;
+
+        Ok(filepath)
+    }
+
+    /// Attach a screenshot, trailer, or other media to an application's store listing (stored
+    /// like any other photo).
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    /// * `media_type` - The kind of media (e.g. "screenshot", "trailer")
+    /// * `filepath` - The path of the file to upload
+    pub fn add_application_media(&self, application_id: i32, media_type: String,
+                                 filepath: String) -> ApiResult<()> {
         let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/iap/create");
-        
+        let url: Url = self.get_url_for("/api/application/media/add");
+
         let form: Form = Form::new()
             .text("application_id", application_id.to_string())
-            .text("title", title.to_string())
-            .text("description", description.to_string())
-            .text("price", price.to_string())
-            .text("data", data.to_string());
-        
-        let response: Response = self.client
+            .text("media_type", media_type)
+            .file("media", filepath)?;
+
+        let request: RequestBuilder = self.client
             .post(url.as_str())
             .headers(headers)
-            .multipart(form)
-            .send()?;
-        
+            .multipart(form);
+
+        let response: Response = self.send(request)?;
+
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
             StatusCode::OK => Ok(()),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
+
+    /// Get the media attached to an application's store listing. The files themselves can be
+    /// downloaded with `get_photo_bytes`.
+    ///
+    /// # Arguments
+    /// * `application_id` - The application's id
+    pub fn get_application_media(&self, application_id: i32) -> ApiResult<Vec<Photo>> {
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string());
+
+        let media_response: GetApplicationMediaResponse =
+            self.get_json("/api/application/media/get", form)?;
+
+        Ok(media_response.media)
+    }
+
+    pub fn create_iap(&self, application_id: i32, title: String, description: String, 
+                      price: f32, data: String) -> ApiResult<()> {
+        if let Some(price_error) = self.check_limits(|limits| limits.check_price("price", price)) {
+            return Err(APIError::Validation(vec![price_error]));
+        }
+
+        let form: FormFields = FormFields::new()
+            .text("application_id", application_id.to_string())
+            .text("title", title.to_string())
+            .text("description", description.to_string())
+            .text("price", price.to_string())
+            .text("data", data.to_string());
+        
+        self.post_form("/api/iap/create", form)?;
+
+        Ok(())
+    }
     
     pub fn get_iap(&self, id: i32) -> ApiResult<IAP> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/iap/get");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("id", id.to_string());
         
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let iap: IAP = from_str(&response.text()?)?;
-                
-                Ok(iap)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.get_json("/api/iap/get", form)
     }
     
     pub fn get_iaps(&self, application_id: i32) -> ApiResult<Vec<IAP>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/application/get-iaps");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string());
         
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() { 
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let iaps_response: GetIAPsResponse = from_str(&response.text()?)?;
-                
-                Ok(iaps_response.iaps)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        let iaps_response: GetIAPsResponse = self.get_json("/api/application/get-iaps", form)?;
+
+        Ok(iaps_response.iaps)
     }
     
-    pub fn upload_cloud_data(&self, user_id: i32, application_id: i32, 
-                             cloud_data: String) -> ApiResult<()> {
+    /// Upload a user's cloud data for an application, overwriting the existing data in the slot.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    /// * `cloud_data` - The data to upload
+    /// * `slot` - The save slot (defaults to `DEFAULT_CLOUD_DATA_SLOT`)
+    /// * `base_date` - The `date` of the cloud data this upload is based on. If specified and the
+    ///   server's copy is newer, `APIError::Conflict` is returned with the server's copy.
+    pub fn upload_cloud_data(&self, user_id: i32, application_id: i32, cloud_data: String,
+                             slot: Option<String>, base_date: Option<String>) -> ApiResult<()> {
         let headers: HeaderMap = self.get_headers();
         let url: Url = self.get_url_for("/api/cloud-data/upload");
         
-        let form: Form = Form::new()
+        let mut form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string())
             .text("application_id", application_id.to_string())
-            .text("data", cloud_data);
+            .text("data", cloud_data)
+            .text("slot", self.get_cloud_data_slot(slot));
+        
+        if let Some(base_date) = base_date {
+            form = form.text("base_date", base_date);
+        }
         
-        let response: Response = self.client
+        let request: RequestBuilder = self.client
             .post(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+            .headers(headers);
+
+        let response: Response = self.send(self.with_fields(request, form))?;
         
         match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::CONFLICT => Err(APIError::Conflict(self.read_json(response)?)),
             StatusCode::CREATED => Ok(()),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
     
-    pub fn get_cloud_data(&self, user_id: i32, application_id: i32) -> ApiResult<CloudData> {
+    /// Upload a user's cloud data for an application from a file, overwriting the existing data in
+    /// the slot. Unlike `upload_cloud_data`, the file is streamed as the multipart body rather
+    /// than being read into memory first (so it's always sent as a multipart form).
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    /// * `path` - The file containing the data to upload
+    /// * `slot` - The save slot (defaults to `DEFAULT_CLOUD_DATA_SLOT`)
+    /// * `base_date` - The `date` of the cloud data this upload is based on. If specified and the
+    ///   server's copy is newer, `APIError::Conflict` is returned with the server's copy.
+    pub fn upload_cloud_data_from_file(&self, user_id: i32, application_id: i32, path: PathBuf,
+                                       slot: Option<String>,
+                                       base_date: Option<String>) -> ApiResult<()> {
         let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/cloud-data/get");
-        
-        let form: Form = Form::new()
+        let url: Url = self.get_url_for("/api/cloud-data/upload");
+
+        let mut form: Form = Form::new()
             .text("user_id", user_id.to_string())
-            .text("application_id", application_id.to_string());
-        
-        let response: Response = self.client
-            .get(url.as_str())
+            .text("application_id", application_id.to_string())
+            .text("slot", self.get_cloud_data_slot(slot));
+
+        if let Some(base_date) = base_date {
+            form = form.text("base_date", base_date);
+        }
+
+        let form: Form = form.file("data", path)?;
+
+        let request: RequestBuilder = self.client
+            .post(url.as_str())
             .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() { 
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let cloud_data: CloudData = from_str(&response.text()?)?;
-                
-                Ok(cloud_data)
-            },
+            .multipart(form);
+
+        let response: Response = self.send(request)?;
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(self.read_text(response)?)),
+            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(self.read_text(response)?)),
+            StatusCode::CONFLICT => Err(APIError::Conflict(self.read_json(response)?)),
+            StatusCode::CREATED => Ok(()),
             _ => Err(APIError::UnhandledStatusCode(response.status()))
         }
     }
+
+    /// Get a user's cloud data for an application.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    /// * `slot` - The save slot (defaults to `DEFAULT_CLOUD_DATA_SLOT`)
+    pub fn get_cloud_data(&self, user_id: i32, application_id: i32,
+                          slot: Option<String>) -> ApiResult<CloudData> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string())
+            .text("application_id", application_id.to_string())
+            .text("slot", self.get_cloud_data_slot(slot));
+        
+        self.get_json("/api/cloud-data/get", form)
+    }
     
-    pub fn delete_cloud_data(&self, user_id: i32, application_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/cloud-data/delete");
+    /// Delete a user's cloud data for an application.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    /// * `slot` - The save slot (defaults to `DEFAULT_CLOUD_DATA_SLOT`)
+    pub fn delete_cloud_data(&self, user_id: i32, application_id: i32,
+                             slot: Option<String>) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string())
+            .text("application_id", application_id.to_string())
+            .text("slot", self.get_cloud_data_slot(slot));
         
-        let form: Form = Form::new()
+        self.delete_form("/api/cloud-data/delete", form)?;
+
+        Ok(())
+    }
+    
+    /// List the names of a user's cloud data slots for an application.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    pub fn list_cloud_data_slots(&self, user_id: i32,
+                                 application_id: i32) -> ApiResult<Vec<String>> {
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string())
             .text("application_id", application_id.to_string());
         
-        let response: Response = self.client
-            .delete(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
+        let slots_response: GetCloudDataSlotsResponse =
+            self.get_json("/api/cloud-data/list-slots", form)?;
+
+        Ok(slots_response.slots)
+    }
+    
+    /// Rename (move) one of a user's cloud data slots for an application.
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
+    /// * `application_id` - The application's id
+    /// * `slot` - The slot's current name
+    /// * `new_slot` - The slot's new name
+    pub fn rename_cloud_data_slot(&self, user_id: i32, application_id: i32, slot: String,
+                                  new_slot: String) -> ApiResult<()> {
+        let form: FormFields = FormFields::new()
+            .text("user_id", user_id.to_string())
+            .text("application_id", application_id.to_string())
+            .text("slot", slot)
+            .text("new_slot", new_slot);
         
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.put_form("/api/cloud-data/rename-slot", form)?;
+
+        Ok(())
     }
     
     pub fn delete_application_cloud_data(&self, application_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/application/delete-cloud-data");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("application_id", application_id.to_string());
         
-        let response: Response = self.client
-            .delete(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.delete_form("/api/application/delete-cloud-data", form)?;
+
+        Ok(())
     }
     
     pub fn update_profile_photo(&self, user_id: i32, photo_id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/update-profile-photo");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string())
             .text("photo_id", photo_id.to_string());
         
-        let response: Response = self.client
-            .put(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() { 
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.put_form("/api/user/update-profile-photo", form)?;
+
+        Ok(())
+    }
+
+    /// Set the authenticated user's status message (a custom status shown to friends alongside
+    /// their activity, e.g. "Looking to play co-op").
+    ///
+    /// # Arguments
+    /// * `message` - The status message, or `None` to clear it
+    pub fn set_status_message(&self, message: Option<String>) -> ApiResult<()> {
+        // An empty status message clears it.
+        let form: FormFields = FormFields::new()
+            .text("status_message", message.unwrap_or_default());
+
+        self.put_form("/api/user/status", form)?;
+
+        Ok(())
     }
     
+    /// Get a user's sessions. The session making the request is flagged as `current`, so a UI can
+    /// label it "This device" (and avoid revoking it by accident).
+    ///
+    /// # Arguments
+    /// * `user_id` - The user's id
     pub fn get_user_sessions(&self, user_id: i32) -> ApiResult<Vec<Session>> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/user/get-sessions");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("user_id", user_id.to_string());
-        
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let sessions_response: GetUserSessionsResponse = from_str(&response.text()?)?;
-                
-                Ok(sessions_response.sessions)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
+
+        let mut sessions_response: GetUserSessionsResponse =
+            self.get_json("/api/user/get-sessions", form)?;
+
+        // Older servers don't flag the current session, but include its identifier.
+        if let Some(session_id) = self.get_session_id() {
+            for session in sessions_response.sessions.iter_mut() {
+                session.current |= session.identifier == session_id;
+            }
         }
+
+        Ok(sessions_response.sessions)
     }
     
     pub fn get_iap_record(&self, id: i32) -> ApiResult<IAPRecord> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/iap-record/get");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("id", id.to_string());
         
-        let response: Response = self.client
-            .get(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() {
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => {
-                let iap_record: IAPRecord = from_str(&response.text()?)?;
-                
-                Ok(iap_record)
-            },
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        }
+        self.get_json("/api/iap-record/get", form)
     }
     
     pub fn acknowledge_iap_record(&self, id: i32) -> ApiResult<()> {
-        let headers: HeaderMap = self.get_headers();
-        let url: Url = self.get_url_for("/api/iap-record/acknowledge");
-        
-        let form: Form = Form::new()
+        let form: FormFields = FormFields::new()
             .text("id", id.to_string());
         
-        let response: Response = self.client
-            .put(url.as_str())
-            .headers(headers)
-            .multipart(form)
-            .send()?;
-        
-        match response.status() { 
-            StatusCode::UNAUTHORIZED => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::FORBIDDEN => Err(APIError::Unauthorized(response.text()?)),
-            StatusCode::BAD_REQUEST => Err(APIError::BadRequest(response.text()?)),
-            StatusCode::OK => Ok(()),
-            _ => Err(APIError::UnhandledStatusCode(response.status()))
-        } 
+        self.put_form("/api/iap-record/acknowledge", form)?;
+
+        Ok(()) 
     }
 }
 
+/// The current version of the CLI config file. Bump this and add a migration step to
+/// `CliTools::migrate_config` whenever the shape of `CliConfig` changes.
+pub const CLI_CONFIG_VERSION: u64 = 1;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CliConfig {
+    version: u64,
     server_url: String,
     server_port: Option<i32>
 }
@@ -1759,15 +3250,63 @@ impl CliTools {
         // Read the config file.
         let data: String = read_to_string(self.get_config_filepath())?;
         
+        // Upgrade older config shapes to the current one.
+        let data: Value = from_str(&data)?;
+        let (data, migrated) = Self::migrate_config(data)?;
+        
         // Load the config.
-        let config: CliConfig = from_str(&data)?;
+        let config: CliConfig = from_value(data)?;
+        
+        // Persist the migrated config so the upgrade only happens once.
+        if migrated {
+            self.write_config_file(&config)?;
+        }
         
         Ok(config)
     }
     
+    /// Upgrade a config file's contents to `CLI_CONFIG_VERSION`, one version at a time. Returns
+    /// the upgraded contents and whether anything was changed.
+    fn migrate_config(mut data: Value) -> Result<(Value, bool), Error> {
+        // Config files written before versioning was introduced have no version field.
+        let version: u64 = data.get("version").and_then(Value::as_u64).unwrap_or(0);
+        
+        if version > CLI_CONFIG_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Config version {} is newer than the supported version {}.",
+                    version, CLI_CONFIG_VERSION
+                )
+            ));
+        }
+        
+        if version < 1 {
+            data = Self::migrate_config_v0(data)?;
+        }
+        
+        Ok((data, version < CLI_CONFIG_VERSION))
+    }
+    
+    /// Version 0 (`server_url` and `server_port`) predates the version field, but otherwise has
+    /// the same shape as version 1.
+    fn migrate_config_v0(mut data: Value) -> Result<Value, Error> {
+        let object = data.as_object_mut().ok_or_else(|| {
+            Error::new(ErrorKind::InvalidData, "The config must be a JSON object.")
+        })?;
+        
+        object.insert("version".to_owned(), json!(1));
+        
+        Ok(data)
+    }
+    
     pub fn write_config(&self, config: CliConfig) -> Result<(), Error> {
+        self.write_config_file(&config)
+    }
+    
+    fn write_config_file(&self, config: &CliConfig) -> Result<(), Error> {
         // Serialize the config data.
-        let config_data: String = to_string_pretty(&config)?;
+        let config_data: String = to_string_pretty(config)?;
         
         // Write the data to the config file.
         let mut file: File = OpenOptions::new()
@@ -0,0 +1,30 @@
+//! Decoding for lists typed as a single comma-separated string (e.g. the CLI's `--tags`). Commas
+//! and backslashes within values are escaped with a backslash, so a tag like `co-op\, local` stays
+//! one tag. This is only an input format: the server is sent lists as arrays in JSON bodies, or as
+//! plain comma-separated fields in forms (see `ApiService::create_application`).
+
+/// Split a comma-separated field into its values, unescaping escaped commas and backslashes. An
+/// empty field is an empty list.
+pub fn decode_list(field: &str) -> Vec<String> {
+    if field.is_empty() {
+        return Vec::new();
+    }
+
+    let mut values: Vec<String> = Vec::new();
+    let mut value: String = String::new();
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                // Keep the escaped character (or a trailing backslash) as-is.
+                value.push(chars.next().unwrap_or('\\'));
+            },
+            ',' => values.push(std::mem::take(&mut value)),
+            _ => value.push(c)
+        }
+    }
+
+    values.push(value);
+    values
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+pub use crate::activity::Activity;
+pub use crate::application::Application;
+pub use crate::application_key::ApplicationKey;
+pub use crate::application_session::ApplicationSession;
+pub use crate::application_version::{ApplicationVersion, NewApplicationVersion};
+pub use crate::catalog::ApplicationDefinition;
+pub use crate::cloud_data::CloudData;
+pub use crate::dashboard::Dashboard;
+pub use crate::deposit::Deposit;
+pub use crate::events::ServerEvent;
+pub use crate::friend::Friend;
+pub use crate::friend_presence::FriendPresence;
+pub use crate::friend_request::FriendRequest;
+pub use crate::iap::IAP;
+pub use crate::iap_record::IAPRecord;
+pub use crate::install_event::InstallEvent;
+pub use crate::invite::Invite;
+pub use crate::photo::Photo;
+pub use crate::platform::{Platform, PlatformInfo};
+pub use crate::public_profile::PublicProfile;
+pub use crate::purchase::{Purchase, PurchaseKind};
+pub use crate::receipt::Receipt;
+pub use crate::revenue_report::RevenueReport;
+pub use crate::sale::Sale;
+pub use crate::server_limits::ServerLimits;
+pub use crate::session::Session;
+pub use crate::transaction::Transaction;
+pub use crate::user::User;
+pub use crate::verification_status::VerificationStatus;
\ No newline at end of file
@@ -5,5 +5,6 @@ pub struct Photo {
     pub id: i32,
     pub filename: String,
     pub subfolder: String,
+    #[serde(alias = "createdAt")]
     pub created_at: String
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::fs::read_to_string;
+use std::process::Command;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+    Windows,
+    Linux,
+    MacOS,
+    Unknown
+}
+
+impl Platform {
+    /// Get the platform this binary was compiled for.
+    pub fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Platform::Windows
+        } else if cfg!(target_os = "linux") {
+            Platform::Linux
+        } else if cfg!(target_os = "macos") {
+            Platform::MacOS
+        } else {
+            Platform::Unknown
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Platform::Windows => "windows",
+            Platform::Linux => "linux",
+            Platform::MacOS => "macos",
+            Platform::Unknown => "unknown"
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlatformInfo {
+    pub os: Platform,
+    pub arch: String,
+    pub os_version: Option<String>
+}
+
+impl PlatformInfo {
+    /// Collect the platform details (OS, architecture, and OS version if it can be detected) of
+    /// the current machine.
+    pub fn current() -> Self {
+        let os: Platform = Platform::current();
+
+        Self {
+            os,
+            arch: String::from(std::env::consts::ARCH),
+            os_version: get_os_version(os)
+        }
+    }
+}
+
+fn get_os_version(os: Platform) -> Option<String> {
+    let version: String = match os {
+        Platform::Windows => get_windows_version()?,
+        Platform::MacOS => {
+            let output = Command::new("sw_vers").arg("-productVersion").output().ok()?;
+
+            String::from_utf8_lossy(&output.stdout).into_owned()
+        },
+        Platform::Linux => read_to_string("/proc/sys/kernel/osrelease").ok()?,
+        Platform::Unknown => return None
+    };
+
+    let version: &str = version.trim();
+
+    if version.is_empty() {
+        None
+    } else {
+        Some(version.to_string())
+    }
+}
+
+/// Get the Windows version (e.g. "10.0.19045") from `RtlGetVersion`, which, unlike
+/// `GetVersionEx`, reports the real version regardless of the executable's manifest. This avoids
+/// spawning a shell (which would flash a console window when called from the daemon).
+#[cfg(windows)]
+fn get_windows_version() -> Option<String> {
+    /// `OSVERSIONINFOW`.
+    #[repr(C)]
+    struct OsVersionInfo {
+        size: u32,
+        major_version: u32,
+        minor_version: u32,
+        build_number: u32,
+        platform_id: u32,
+        csd_version: [u16; 128]
+    }
+
+    #[link(name = "ntdll")]
+    extern "system" {
+        fn RtlGetVersion(info: *mut OsVersionInfo) -> i32;
+    }
+
+    let mut info: OsVersionInfo = OsVersionInfo {
+        size: std::mem::size_of::<OsVersionInfo>() as u32,
+        major_version: 0,
+        minor_version: 0,
+        build_number: 0,
+        platform_id: 0,
+        csd_version: [0; 128]
+    };
+
+    // SAFETY: `info` is a valid, writable `OSVERSIONINFOW` with its size set, as required.
+    let status: i32 = unsafe { RtlGetVersion(&mut info) };
+
+    if status != 0 {
+        return None;
+    }
+
+    Some(format!("{}.{}.{}", info.major_version, info.minor_version, info.build_number))
+}
+
+#[cfg(not(windows))]
+fn get_windows_version() -> Option<String> {
+    None
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+pub use crate::{ApiResult, ApiService};
+pub use crate::api_error::APIError;
+pub use crate::batch::{Batch, BatchResult};
+pub use crate::daemon_client::{DaemonClient, DaemonMessage, DaemonResponse};
+pub use crate::deep_link::DeepLink;
+pub use crate::device_info::DeviceInfo;
+pub use crate::diagnosis::{DiagnosticStage, Diagnosis};
+pub use crate::download::CollisionPolicy;
+pub use crate::launch_context::{parse_launch_context, LaunchContext};
+pub use crate::models::*;
+pub use crate::server_error_code::ServerErrorCode;
+pub use crate::timestamp::Timestamped;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use std::net::TcpStream;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, json};
+use tungstenite::{Message, WebSocket};
+use tungstenite::stream::MaybeTlsStream;
+use crate::activity::Activity;
+use crate::api_error::APIError;
+use crate::invite::Invite;
+use crate::ApiResult;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type", content = "data", rename_all = "lowercase")]
+pub enum PresenceEvent {
+    Activity {
+        user_id: i32,
+        activity: Activity
+    },
+    Invite(Invite)
+}
+
+/// A live websocket connection delivering friend presence and invite events.
+pub struct PresenceChannel {
+    socket: WebSocket<MaybeTlsStream<TcpStream>>
+}
+
+impl PresenceChannel {
+    /// Connect to the presence websocket and authenticate with the specified session id.
+    ///
+    /// # Arguments
+    /// * `url` - The websocket URL (ws:// or wss://)
+    /// * `session_id` - The session id to authenticate with
+    pub fn connect(url: &str, session_id: &str) -> ApiResult<Self> {
+        let (mut socket, _) = tungstenite::connect(url)?;
+
+        let authentication = json!({
+            "type": "authenticate",
+            "data": {
+                "session_id": session_id
+            }
+        });
+
+        socket.send(Message::text(authentication.to_string()))?;
+
+        Ok(Self { socket })
+    }
+
+    pub fn close(&mut self) -> ApiResult<()> {
+        self.socket.close(None)?;
+
+        Ok(())
+    }
+}
+
+impl Iterator for PresenceChannel {
+    type Item = ApiResult<PresenceEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let message: Message = match self.socket.read() {
+                Ok(message) => message,
+                Err(tungstenite::Error::ConnectionClosed) => return None,
+                Err(err) => return Some(Err(APIError::from(err)))
+            };
+
+            match message {
+                Message::Text(text) => return Some(from_str(&text).map_err(APIError::from)),
+                Message::Close(_) => return None,
+                // Pings are answered automatically; anything else isn't a presence event.
+                _ => continue
+            }
+        }
+    }
+}
\ No newline at end of file
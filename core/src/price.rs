@@ -0,0 +1,72 @@
+//! Serde helpers for prices and balances, for use with `#[serde(with = "crate::price")]`.
+//!
+//! Prices are sent by the server either as JSON numbers or as decimal strings. Parsing the
+//! decimal text straight into an `f32` gives the closest `f32` to the exact price. Serializing
+//! writes the shortest decimal that round-trips, so `19.99` is written back as `19.99` rather than
+//! `19.989999771118164`, which is what widening it to an `f64` would give.
+
+use std::fmt;
+use std::fmt::Formatter;
+use serde::de::{Error, Visitor};
+use serde::{Deserializer, Serializer};
+
+struct PriceVisitor;
+
+impl Visitor<'_> for PriceVisitor {
+    type Value = f32;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a price, as a number or a decimal string")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<f32, E> {
+        value.trim().parse().map_err(|_| E::custom(format!("invalid price: {:?}", value)))
+    }
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<f32, E> {
+        // Go through the shortest decimal form of the number, so it's only rounded once.
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<f32, E> {
+        Ok(value as f32)
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<f32, E> {
+        Ok(value as f32)
+    }
+}
+
+pub fn serialize<S: Serializer>(price: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+    // The shortest decimal form of an `f32` always parses back into an `f64` of the same value.
+    serializer.serialize_f64(price.to_string().parse().unwrap())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    deserializer.deserialize_any(PriceVisitor)
+}
+
+/// The same, for optional prices (e.g. `#[serde(with = "crate::price::option")]`).
+pub mod option {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    struct Price(#[serde(with = "crate::price")] f32);
+
+    pub fn serialize<S: Serializer>(price: &Option<f32>,
+                                    serializer: S) -> Result<S::Ok, S::Error> {
+        match price {
+            Some(price) => super::serialize(price, serializer),
+            None => serializer.serialize_none()
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let price: Option<Price> = Option::deserialize(deserializer)?;
+
+        Ok(price.map(|Price(price)| price))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use crate::activity::Activity;
+use crate::user::User;
+
+/// The publicly visible part of a user's profile (no email address, password, or balance). The
+/// profile photo id is absent for users who haven't set one.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PublicProfile {
+    pub id: i32,
+    pub username: String,
+    pub name: String,
+    #[serde(alias = "profilePhotoId")]
+    pub profile_photo_id: Option<i32>,
+    pub activity: Activity,
+    #[serde(alias = "statusMessage")]
+    pub status_message: Option<String>,
+    pub joined: String
+}
+
+impl From<User> for PublicProfile {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            username: user.username,
+            name: user.name,
+            profile_photo_id: user.profile_photo_id,
+            activity: user.activity,
+            status_message: user.status_message,
+            joined: user.joined
+        }
+    }
+}
\ No newline at end of file
@@ -1,14 +1,37 @@
 use serde::{Deserialize, Serialize};
 
+/// What a purchase was for, with the id that's meaningful for that kind of purchase.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseKind {
+    Application(i32),
+    Iap(i32)
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Purchase {
     pub id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
+    #[serde(alias = "iapId")]
     pub iap_id: i32,
+    #[serde(alias = "userId")]
     pub user_id: i32,
     pub r#type: String,
     pub source: String,
+    #[serde(with = "crate::price")]
     pub price: f32,
     pub key: String,
     pub date: String
+}
+
+impl Purchase {
+    /// Get what the purchase was for, based on its type. Only IAP purchases have a meaningful IAP
+    /// id; every other type (e.g. an application or preorder purchase) is for the application.
+    pub fn kind(&self) -> PurchaseKind {
+        if self.r#type.eq_ignore_ascii_case("iap") {
+            PurchaseKind::Iap(self.iap_id)
+        } else {
+            PurchaseKind::Application(self.application_id)
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A token bucket, pacing requests to an average rate while allowing short bursts (of up to one
+/// second's worth of requests).
+pub struct RateLimiter {
+    requests_per_second: f64,
+    capacity: f64,
+    // The tokens available, and when they were last topped up.
+    bucket: Mutex<(f64, Instant)>
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: f64) -> Self {
+        let capacity: f64 = requests_per_second.max(1.0);
+
+        Self {
+            requests_per_second,
+            capacity,
+            bucket: Mutex::new((capacity, Instant::now()))
+        }
+    }
+
+    /// Take a token, blocking until one is available.
+    pub fn acquire(&self) {
+        let wait: Duration = {
+            let mut bucket = self.bucket.lock().unwrap();
+            let (tokens, last_refill) = *bucket;
+
+            let now: Instant = Instant::now();
+            let refilled: f64 = last_refill.elapsed().as_secs_f64() * self.requests_per_second;
+            let tokens: f64 = (tokens + refilled).min(self.capacity) - 1.0;
+
+            // A negative balance is a reservation; wait until it has been paid back.
+            *bucket = (tokens, now);
+
+            if tokens >= 0.0 {
+                return;
+            }
+
+            Duration::from_secs_f64(-tokens / self.requests_per_second)
+        };
+
+        thread::sleep(wait);
+    }
+}
\ No newline at end of file
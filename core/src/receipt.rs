@@ -0,0 +1,60 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::fs::write;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::purchase::PurchaseKind;
+use crate::ApiResult;
+
+/// A receipt for a purchase (see `ApiService::get_purchase_receipt`), for expense reports and the
+/// like.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Receipt {
+    pub purchase_id: i32,
+    pub kind: PurchaseKind,
+    // The application's name, or the IAP's title.
+    pub item: String,
+    #[serde(with = "crate::price")]
+    pub price: f32,
+    // The tax included in the price (absent when the server doesn't itemize it).
+    #[serde(default, with = "crate::price::option")]
+    pub tax: Option<f32>,
+    pub date: String,
+    pub key: String,
+    pub buyer: String
+}
+
+impl Receipt {
+    /// Render the receipt as a plain text document.
+    pub fn to_text(&self) -> String {
+        self.to_string()
+    }
+
+    /// Save the receipt to a file, as plain text.
+    ///
+    /// # Arguments
+    /// * `path` - The file to write the receipt to
+    pub fn save(&self, path: &Path) -> ApiResult<()> {
+        write(path, self.to_text())?;
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Frogworks Receipt")?;
+        writeln!(f, "=================")?;
+        writeln!(f, "Purchase: #{}", self.purchase_id)?;
+        writeln!(f, "Date:     {}", self.date)?;
+        writeln!(f, "Buyer:    {}", self.buyer)?;
+        writeln!(f, "Item:     {}", self.item)?;
+        writeln!(f, "Key:      {}", self.key)?;
+
+        if let Some(tax) = self.tax {
+            writeln!(f, "Tax:      {:.2}", tax)?;
+        }
+
+        write!(f, "Total:    {:.2}", self.price)
+    }
+}
\ No newline at end of file
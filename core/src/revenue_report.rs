@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RevenueReport {
+    #[serde(with = "crate::price")]
+    pub gross: f32,
+    #[serde(with = "crate::price")]
+    pub net: f32,
+    #[serde(alias = "unitsSold")]
+    pub units_sold: u64,
+    pub refunds: u64
+}
\ No newline at end of file
@@ -3,10 +3,14 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Sale {
     pub id: i32,
+    #[serde(alias = "applicationId")]
     pub application_id: i32,
     pub title: String,
     pub description: String,
+    #[serde(with = "crate::price")]
     pub price: f32,
+    #[serde(alias = "startDate")]
     pub start_date: String,
+    #[serde(alias = "endDate")]
     pub end_date: String
 }
\ No newline at end of file
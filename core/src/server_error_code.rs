@@ -0,0 +1,97 @@
+use std::fmt;
+use std::fmt::Formatter;
+use serde_json::{from_str, Value};
+
+/// An error identifier sent by the server in the body of a 400/401/403 response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerErrorCode {
+    EmailTaken,
+    UsernameTaken,
+    PackageNameTaken,
+    InvalidCredentials,
+    InvalidVerificationCode,
+    VerificationCodeExpired,
+    InsufficientBalance,
+    AlreadyOwned,
+    NotOwned,
+    InvalidKey,
+    KeyAlreadyRedeemed,
+    NotDeveloper,
+    NotFriends,
+    /// An identifier this client doesn't know about (yet).
+    Other(String)
+}
+
+impl ServerErrorCode {
+    /// Parse the error identifier from a response body, which is either the bare identifier
+    /// (optionally as a JSON string) or a JSON object with an `error` or `code` field.
+    pub fn parse(body: &str) -> Option<Self> {
+        let body: &str = body.trim();
+
+        let identifier: String = match from_str::<Value>(body) {
+            Ok(Value::String(identifier)) => identifier,
+            Ok(Value::Object(map)) => {
+                match map.get("error").or_else(|| map.get("code")) {
+                    Some(Value::String(identifier)) => identifier.to_owned(),
+                    _ => return None
+                }
+            },
+            Ok(_) => return None,
+            Err(_) => body.to_string()
+        };
+
+        // Free-text messages aren't identifiers.
+        let is_identifier: bool = !identifier.is_empty() && identifier.chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+        if !is_identifier {
+            return None;
+        }
+
+        Some(Self::from_identifier(&identifier))
+    }
+
+    pub fn from_identifier(identifier: &str) -> Self {
+        match identifier {
+            "email_taken" => ServerErrorCode::EmailTaken,
+            "username_taken" => ServerErrorCode::UsernameTaken,
+            "package_name_taken" => ServerErrorCode::PackageNameTaken,
+            "invalid_credentials" => ServerErrorCode::InvalidCredentials,
+            "invalid_verification_code" => ServerErrorCode::InvalidVerificationCode,
+            "verification_code_expired" => ServerErrorCode::VerificationCodeExpired,
+            "insufficient_balance" => ServerErrorCode::InsufficientBalance,
+            "already_owned" => ServerErrorCode::AlreadyOwned,
+            "not_owned" => ServerErrorCode::NotOwned,
+            "invalid_key" => ServerErrorCode::InvalidKey,
+            "key_already_redeemed" => ServerErrorCode::KeyAlreadyRedeemed,
+            "not_developer" => ServerErrorCode::NotDeveloper,
+            "not_friends" => ServerErrorCode::NotFriends,
+            _ => ServerErrorCode::Other(identifier.to_string())
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServerErrorCode::EmailTaken => "email_taken",
+            ServerErrorCode::UsernameTaken => "username_taken",
+            ServerErrorCode::PackageNameTaken => "package_name_taken",
+            ServerErrorCode::InvalidCredentials => "invalid_credentials",
+            ServerErrorCode::InvalidVerificationCode => "invalid_verification_code",
+            ServerErrorCode::VerificationCodeExpired => "verification_code_expired",
+            ServerErrorCode::InsufficientBalance => "insufficient_balance",
+            ServerErrorCode::AlreadyOwned => "already_owned",
+            ServerErrorCode::NotOwned => "not_owned",
+            ServerErrorCode::InvalidKey => "invalid_key",
+            ServerErrorCode::KeyAlreadyRedeemed => "key_already_redeemed",
+            ServerErrorCode::NotDeveloper => "not_developer",
+            ServerErrorCode::NotFriends => "not_friends",
+            ServerErrorCode::Other(ref identifier) => identifier
+        }
+    }
+}
+
+impl fmt::Display for ServerErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use crate::api_error::FieldError;
+
+/// The limits the server enforces (see `ApiService::get_server_limits`). Limits the server doesn't
+/// report are `None`, and aren't checked.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ServerLimits {
+    #[serde(alias = "maxUploadBytes")]
+    pub max_upload_bytes: Option<u64>,
+    /// The most keys that can be generated at once. Reported for information only: there's no key
+    /// generation method to check it against yet.
+    #[serde(alias = "maxKeyBatch")]
+    pub max_key_batch: Option<u32>,
+    #[serde(alias = "minPrice", default, with = "crate::price::option")]
+    pub min_price: Option<f32>,
+    #[serde(alias = "maxPrice", default, with = "crate::price::option")]
+    pub max_price: Option<f32>
+}
+
+impl ServerLimits {
+    /// Check the size of a file being uploaded.
+    ///
+    /// # Arguments
+    /// * `field` - The field the file is sent as
+    /// * `bytes` - The file's size
+    pub fn check_upload_size(&self, field: &str, bytes: u64) -> Option<FieldError> {
+        let max_upload_bytes: u64 = self.max_upload_bytes?;
+
+        if bytes <= max_upload_bytes {
+            return None;
+        }
+
+        Some(FieldError {
+            field: field.to_string(),
+            message: format!("The file is {} bytes, but the maximum is {} bytes.", bytes,
+                             max_upload_bytes)
+        })
+    }
+
+    /// Check a price is within the allowed bounds.
+    ///
+    /// # Arguments
+    /// * `field` - The field the price is sent as
+    /// * `price` - The price
+    pub fn check_price(&self, field: &str, price: f32) -> Option<FieldError> {
+        let message: String = match (self.min_price, self.max_price) {
+            (Some(min_price), _) if price < min_price =>
+                format!("The price must be at least {:.2}.", min_price),
+            (_, Some(max_price)) if price > max_price =>
+                format!("The price must be at most {:.2}.", max_price),
+            _ => return None
+        };
+
+        Some(FieldError {
+            field: field.to_string(),
+            message
+        })
+    }
+}
\ No newline at end of file
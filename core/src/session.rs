@@ -4,10 +4,18 @@ use serde::{Deserialize, Serialize};
 pub struct Session {
     pub id: i32,
     pub identifier: String,
+    #[serde(alias = "userId")]
     pub user_id: i32,
     pub hostname: String,
+    #[serde(alias = "macAddress")]
     pub mac_address: String,
     pub platform: String,
+    #[serde(alias = "startDate")]
     pub start_date: String,
-    pub last_activity: String
+    #[serde(alias = "lastActivity")]
+    pub last_activity: String,
+    // Whether this is the session making the request (i.e. "this device"), as marked by the
+    // server.
+    #[serde(default)]
+    pub current: bool
 }
\ No newline at end of file
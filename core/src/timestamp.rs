@@ -0,0 +1,80 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use crate::application::Application;
+use crate::application_session::ApplicationSession;
+use crate::application_version::ApplicationVersion;
+use crate::cloud_data::CloudData;
+use crate::deposit::Deposit;
+use crate::friend::Friend;
+use crate::friend_request::FriendRequest;
+use crate::iap_record::IAPRecord;
+use crate::invite::Invite;
+use crate::photo::Photo;
+use crate::public_profile::PublicProfile;
+use crate::purchase::Purchase;
+use crate::receipt::Receipt;
+use crate::sale::Sale;
+use crate::session::Session;
+use crate::transaction::Transaction;
+use crate::user::User;
+
+/// A record with a point in time it can be sorted or grouped by, whatever its timestamp field is
+/// called.
+pub trait Timestamped {
+    /// The record's timestamp, as sent by the server.
+    fn timestamp_str(&self) -> &str;
+
+    /// The record's timestamp, or `None` if it couldn't be parsed.
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(self.timestamp_str())
+    }
+}
+
+/// Parse a timestamp sent by the server: RFC 3339, a date and time without a timezone (assumed to
+/// be UTC), or a plain date.
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.with_timezone(&Utc));
+    }
+
+    for format in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"] {
+        if let Ok(timestamp) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(timestamp.and_utc());
+        }
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|timestamp| timestamp.and_utc())
+}
+
+macro_rules! impl_timestamped {
+    ($($model:ty => $field:ident),* $(,)?) => {
+        $(
+            impl Timestamped for $model {
+                fn timestamp_str(&self) -> &str {
+                    &self.$field
+                }
+            }
+        )*
+    };
+}
+
+impl_timestamped! {
+    Application => release_date,
+    ApplicationSession => date,
+    ApplicationVersion => release_date,
+    CloudData => date,
+    Deposit => date,
+    Friend => date,
+    FriendRequest => date,
+    IAPRecord => date,
+    Invite => date,
+    Photo => created_at,
+    PublicProfile => joined,
+    Purchase => date,
+    Receipt => date,
+    Sale => start_date,
+    Session => start_date,
+    Transaction => date,
+    User => joined
+}
\ No newline at end of file
@@ -1,18 +1,26 @@
 use serde::{Deserialize, Serialize};
 use crate::activity::Activity;
 
+/// A user account. The balance is only included for the current user, and the profile photo id
+/// is absent for users who haven't set one.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct User {
     pub id: i32,
     pub identifier: String,
     pub username: String,
     pub name: String,
+    #[serde(alias = "emailAddress")]
     pub email_address: Option<String>,
     pub password: Option<String>,
     pub joined: String,
-    pub balance: f32,
-    pub profile_photo_id: i32,
+    #[serde(default, with = "crate::price::option")]
+    pub balance: Option<f32>,
+    #[serde(alias = "profilePhotoId")]
+    pub profile_photo_id: Option<i32>,
     pub activity: Activity,
+    // A custom status (e.g. "AFK"), shown alongside the activity (absent if none is set).
+    #[serde(alias = "statusMessage")]
+    pub status_message: Option<String>,
     pub developer: bool,
     pub administrator: bool,
     pub verified: bool
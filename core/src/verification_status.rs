@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// The outcome of checking an email verification code.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationStatus {
+    /// The code was correct, and the email address is now verified.
+    Verified,
+    /// The email address had already been verified (the code isn't checked).
+    AlreadyVerified,
+    Incorrect,
+    /// The code was correct, but has expired (a new one must be requested).
+    Expired
+}
+
+impl VerificationStatus {
+    /// Whether the email address is verified (now or previously).
+    pub fn is_verified(&self) -> bool {
+        matches!(self, VerificationStatus::Verified | VerificationStatus::AlreadyVerified)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+use std::path::PathBuf;
+use serde_json::{from_str, json, Value};
+use frogworks_core::{CliTools, CLI_CONFIG_VERSION};
+
+/// A config file as written before the version field was introduced.
+const VERSION_0_CONFIG: &str = r#"{
+  "server_url": "http://192.168.1.16/",
+  "server_port": 8080
+}"#;
+
+/// Write a config file into a folder unique to the test, returning the folder and the file.
+fn write_config(name: &str, contents: &str) -> (PathBuf, PathBuf) {
+    let folder: PathBuf = std::env::temp_dir()
+        .join(format!("frogworks-cli-config-test-{}-{}", name, std::process::id()));
+    let filepath: PathBuf = folder.join("config.json");
+
+    create_dir_all(&folder).unwrap();
+    write(&filepath, contents).unwrap();
+
+    (folder, filepath)
+}
+
+#[test]
+fn migrates_unversioned_configs() {
+    let (folder, filepath) = write_config("v0", VERSION_0_CONFIG);
+    let cli_tools: CliTools = CliTools::new(filepath.display().to_string());
+
+    cli_tools.get_config().unwrap();
+
+    // The migrated config is written back, keeping the existing settings.
+    let migrated: Value = from_str(&read_to_string(&filepath).unwrap()).unwrap();
+
+    assert_eq!(migrated, json!({
+        "version": CLI_CONFIG_VERSION,
+        "server_url": "http://192.168.1.16/",
+        "server_port": 8080
+    }));
+
+    // Loading the migrated config leaves it as it is.
+    cli_tools.get_config().unwrap();
+
+    assert_eq!(from_str::<Value>(&read_to_string(&filepath).unwrap()).unwrap(), migrated);
+
+    remove_dir_all(folder).unwrap();
+}
+
+#[test]
+fn rejects_configs_from_newer_versions() {
+    let config: String = json!({
+        "version": CLI_CONFIG_VERSION + 1,
+        "server_url": "http://192.168.1.16/",
+        "server_port": null
+    }).to_string();
+    let (folder, filepath) = write_config("newer", &config);
+
+    assert!(CliTools::new(filepath.display().to_string()).get_config().is_err());
+    assert_eq!(read_to_string(&filepath).unwrap(), config);
+
+    remove_dir_all(folder).unwrap();
+}
@@ -0,0 +1,53 @@
+use frogworks_core::deep_link::{deep_link_install, deep_link_launch, deep_link_store, DeepLink};
+
+#[test]
+fn round_trips_generated_links() {
+    for application_id in [1, 42, i32::MAX] {
+        assert_eq!(
+            DeepLink::parse(&deep_link_install(application_id)),
+            Some(DeepLink::Install(application_id))
+        );
+        assert_eq!(
+            DeepLink::parse(&deep_link_launch(application_id)),
+            Some(DeepLink::Launch(application_id))
+        );
+        assert_eq!(
+            DeepLink::parse(&deep_link_store(application_id)),
+            Some(DeepLink::Store(application_id))
+        );
+    }
+}
+
+#[test]
+fn accepts_a_trailing_slash() {
+    assert_eq!(DeepLink::parse("frogworks://install/42/"), Some(DeepLink::Install(42)));
+}
+
+#[test]
+fn rejects_a_wrong_scheme() {
+    assert_eq!(DeepLink::parse("https://install/42"), None);
+    assert_eq!(DeepLink::parse("frogworks:install/42"), None);
+}
+
+#[test]
+fn rejects_an_unknown_action() {
+    assert_eq!(DeepLink::parse("frogworks://uninstall/42"), None);
+    assert_eq!(DeepLink::parse("frogworks://42"), None);
+}
+
+#[test]
+fn rejects_trailing_path_segments() {
+    assert_eq!(DeepLink::parse("frogworks://install/42/extra"), None);
+    assert_eq!(DeepLink::parse("frogworks://install/42//"), None);
+}
+
+#[test]
+fn rejects_invalid_ids() {
+    assert_eq!(DeepLink::parse("frogworks://install/abc"), None);
+    assert_eq!(DeepLink::parse("frogworks://install/"), None);
+    assert_eq!(DeepLink::parse("frogworks://install/4.2"), None);
+    assert_eq!(DeepLink::parse("frogworks://install/+42"), None);
+    assert_eq!(DeepLink::parse("frogworks://install/-42"), None);
+    assert_eq!(DeepLink::parse("frogworks://install/0"), None);
+    assert_eq!(DeepLink::parse("frogworks://install/99999999999"), None);
+}
@@ -0,0 +1,223 @@
+use std::fs::{read, read_dir, remove_dir_all};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::thread;
+use serde_json::json;
+use frogworks_core::download::CollisionPolicy;
+use frogworks_core::install_event::InstallEvent;
+use frogworks_core::ApiService;
+
+const FILE_CONTENTS: &[u8] = b"a version's contents, sent in several chunks";
+
+/// Filenames that would write outside the folder they're joined onto.
+const UNSAFE_FILENAMES: [&str; 6] = [
+    "../escaped.bin",
+    "/tmp/escaped.bin",
+    "nested/game.bin",
+    "nested\\game.bin",
+    "..",
+    "C:game.bin"
+];
+
+/// Read a request, returning its path (the request's body, if any, is skipped).
+fn read_request(stream: &TcpStream) -> String {
+    let mut reader: BufReader<&TcpStream> = BufReader::new(stream);
+    let mut request_line: String = String::new();
+    reader.read_line(&mut request_line).unwrap();
+
+    let mut content_length: usize = 0;
+
+    loop {
+        let mut line: String = String::new();
+        reader.read_line(&mut line).unwrap();
+
+        if line.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+    }
+
+    let mut body: Vec<u8> = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+
+    request_line.split_whitespace().nth(1).unwrap().to_string()
+}
+
+/// How the fake backend sends a version's download.
+#[derive(Clone, Copy)]
+enum Download {
+    /// Using chunked transfer encoding (so without a `Content-Length`).
+    Chunked,
+    /// With a `Content-Length` larger than the data sent before the connection is closed.
+    Truncated
+}
+
+/// Start a fake backend on an ephemeral port, which serves a version with the specified filename
+/// and then its download, returning its base URL.
+fn start_fake_backend(filename: &'static str, download: Download) -> String {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url: String = format!("http://{}", listener.local_addr().unwrap());
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream: TcpStream = stream.unwrap();
+            let path: String = read_request(&stream);
+
+            if path.starts_with("/api/application/versions/get-specific") {
+                let version: String = json!({
+                    "id": 7,
+                    "application_id": 1,
+                    "name": "1.0.0",
+                    "platform": "linux",
+                    "architecture": null,
+                    "branch": null,
+                    "release_date": "2024-01-01",
+                    "filename": filename,
+                    "executable": "game.bin",
+                    "changelog": null
+                }).to_string();
+
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                                Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                       version.len(), version).unwrap();
+            } else if let Download::Truncated = download {
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\
+                                Connection: close\r\n\r\n", FILE_CONTENTS.len() * 2).unwrap();
+                stream.write_all(FILE_CONTENTS).unwrap();
+            } else {
+                stream.write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\
+                                   Connection: close\r\n\r\n").unwrap();
+
+                for chunk in FILE_CONTENTS.chunks(10) {
+                    write!(stream, "{:x}\r\n", chunk.len()).unwrap();
+                    stream.write_all(chunk).unwrap();
+                    stream.write_all(b"\r\n").unwrap();
+                    stream.flush().unwrap();
+                }
+
+                stream.write_all(b"0\r\n\r\n").unwrap();
+            }
+        }
+    });
+
+    base_url
+}
+
+/// Get a folder for a test's install, unique to the test run.
+fn test_folder(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("frogworks-download-test-{}-{}", name, std::process::id()))
+}
+
+#[test]
+fn installs_chunked_downloads_without_a_total() {
+    let api_service: ApiService =
+        ApiService::new(start_fake_backend("game.bin", Download::Chunked));
+    let install_folder: PathBuf = test_folder("chunked");
+
+    let mut events: Vec<InstallEvent> = Vec::new();
+    let filepath: PathBuf = api_service
+        .install_application_version(7, install_folder.clone(), |event| events.push(event))
+        .unwrap();
+
+    assert_eq!(read(&filepath).unwrap(), FILE_CONTENTS);
+
+    // Every progress event reports the bytes downloaded so far, with no total (or fraction).
+    let progress: Vec<u64> = events.iter()
+        .filter_map(|event| match event {
+            InstallEvent::DownloadProgress { done, total } => {
+                assert_eq!(*total, None);
+                assert_eq!(event.progress(), None);
+
+                Some(*done)
+            },
+            _ => None
+        })
+        .collect();
+
+    assert_eq!(progress.first(), Some(&0));
+    assert_eq!(progress.last(), Some(&(FILE_CONTENTS.len() as u64)));
+    assert!(progress.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    assert_eq!(&events[events.len() - 2..], &[InstallEvent::Verifying, InstallEvent::Done]);
+
+    remove_dir_all(install_folder).unwrap();
+}
+
+#[test]
+fn removes_the_partial_file_when_a_download_fails() {
+    let api_service: ApiService =
+        ApiService::new(start_fake_backend("game.bin", Download::Truncated));
+    let install_folder: PathBuf = test_folder("truncated");
+
+    let result = api_service.install_application_version(7, install_folder.clone(), |_| {});
+
+    assert!(result.is_err());
+    assert_eq!(read_dir(&install_folder).unwrap().count(), 0);
+
+    remove_dir_all(install_folder).unwrap();
+}
+
+#[test]
+fn rejects_filenames_outside_the_install_folder() {
+    for filename in UNSAFE_FILENAMES {
+        let api_service: ApiService =
+            ApiService::new(start_fake_backend(filename, Download::Chunked));
+        let install_folder: PathBuf = test_folder("unsafe");
+
+        let result = api_service.install_application_version(7, install_folder.clone(), |_| {});
+
+        assert!(result.is_err(), "{} was accepted", filename);
+        assert!(!install_folder.exists());
+    }
+}
+
+#[test]
+fn downloads_without_leaving_a_partial_file() {
+    let api_service: ApiService =
+        ApiService::new(start_fake_backend("game.bin", Download::Chunked));
+    let download_folder: PathBuf = test_folder("download");
+
+    let filepath: PathBuf = api_service
+        .download_application_version(7, download_folder.display().to_string(),
+                                      CollisionPolicy::Overwrite)
+        .unwrap();
+
+    assert_eq!(read(&filepath).unwrap(), FILE_CONTENTS);
+    assert_eq!(read_dir(&download_folder).unwrap().count(), 1);
+
+    remove_dir_all(download_folder).unwrap();
+}
+
+#[test]
+fn rejects_filenames_outside_the_download_folder() {
+    for filename in UNSAFE_FILENAMES {
+        let api_service: ApiService =
+            ApiService::new(start_fake_backend(filename, Download::Chunked));
+        let download_folder: PathBuf = test_folder("unsafe-download");
+
+        let result = api_service.download_application_version(
+            7,
+            download_folder.display().to_string(),
+            CollisionPolicy::Overwrite
+        );
+
+        assert!(result.is_err(), "{} was accepted", filename);
+        assert!(!download_folder.exists());
+    }
+}
+
+#[test]
+fn reports_progress_fractions_when_the_total_is_known() {
+    let event: InstallEvent = InstallEvent::DownloadProgress { done: 25, total: Some(100) };
+    assert_eq!(event.progress(), Some(0.25));
+
+    // An empty download has no meaningful fraction (rather than dividing by zero).
+    let event: InstallEvent = InstallEvent::DownloadProgress { done: 0, total: Some(0) };
+    assert_eq!(event.progress(), None);
+}
\ No newline at end of file
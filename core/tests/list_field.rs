@@ -0,0 +1,51 @@
+use frogworks_core::list_field::decode_list;
+
+/// Escape values the way `decode_list` expects, and join them into a single field.
+fn encode(values: &[&str]) -> String {
+    values.iter()
+        .map(|value| value.replace('\\', "\\\\").replace(',', "\\,"))
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+#[test]
+fn decodes_plain_lists() {
+    assert_eq!(decode_list("action,rpg,co-op"), vec!["action", "rpg", "co-op"]);
+    assert_eq!(decode_list("single"), vec!["single"]);
+}
+
+#[test]
+fn decodes_an_empty_field_as_an_empty_list() {
+    assert!(decode_list("").is_empty());
+}
+
+#[test]
+fn keeps_empty_values() {
+    assert_eq!(decode_list("a,,b"), vec!["a", "", "b"]);
+    assert_eq!(decode_list("a,"), vec!["a", ""]);
+}
+
+#[test]
+fn keeps_escaped_separators_within_values() {
+    assert_eq!(decode_list("co-op\\, local,rpg"), vec!["co-op, local", "rpg"]);
+    assert_eq!(decode_list("back\\\\slash,rpg"), vec!["back\\slash", "rpg"]);
+}
+
+#[test]
+fn keeps_a_trailing_backslash() {
+    assert_eq!(decode_list("rpg\\"), vec!["rpg\\"]);
+}
+
+#[test]
+fn round_trips_escaped_values() {
+    let lists: [&[&str]; 4] = [
+        &["action", "rpg"],
+        &["co-op, local", "rpg"],
+        &["back\\slash", "comma,\\,mix", ""],
+        &["\\", ","]
+    ];
+
+    for values in lists {
+        assert_eq!(decode_list(&encode(values)), values);
+    }
+}
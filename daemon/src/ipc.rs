@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_value, to_vec};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+pub use frogworks_core::daemon_client::{get_daemon_address, DaemonMessage, DaemonResponse,
+                                        DAEMON_IP, DAEMON_PORT};
+
+pub const HEARTBEAT_PORT: u16 = 57223;
+
+/// The largest message a client may send, so a misbehaving client can't exhaust memory.
+const MAX_MESSAGE_SIZE: u64 = 64 * 1024;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ArgsMessage {
+    pub args: Vec<String>
+}
+
+/// Dispatch a message to the appropriate handler, returning the response for the client.
+pub fn handle_message<F>(message: DaemonMessage, handle_args: &F) -> DaemonResponse
+where
+    F: Fn(Vec<String>)
+{
+    match message.r#type.as_str() {
+        "args" => {
+            // Parse the arguments.
+            let args_message: ArgsMessage = match from_value(message.data) {
+                Ok(args) => ArgsMessage { args },
+                Err(e) => {
+                    eprintln!("Failed to deserialize arguments: {}", e);
+                    return DaemonResponse::error("Invalid arguments.");
+                }
+            };
+
+            // Pass the arguments along so they can be handled.
+            handle_args(args_message.args);
+
+            DaemonResponse::ok()
+        },
+        // Used by clients to check that the daemon is running.
+        "ping" => DaemonResponse::ok(),
+        _ => {
+            println!("Unknown message type: {}", message.r#type);
+
+            DaemonResponse::error("Unknown message type.")
+        }
+    }
+}
+
+async fn handle_client<F>(mut stream: TcpStream, handle_args: Arc<F>)
+where
+    F: Fn(Vec<String>)
+{
+    // Clients shut down their side of the connection once the whole message has been sent.
+    let mut buffer: Vec<u8> = Vec::new();
+
+    if let Err(e) = (&mut stream).take(MAX_MESSAGE_SIZE).read_to_end(&mut buffer).await {
+        eprintln!("Failed to read from TCP connection: {}", e);
+        return;
+    }
+
+    let buffer: Cow<str> = String::from_utf8_lossy(&buffer);
+
+    // Attempt to deserialize the JSON.
+    let response: DaemonResponse = match serde_json::from_str(&buffer) {
+        Ok(message) => {
+            // A panicking handler only fails this message, rather than the daemon's task.
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                handle_message(message, handle_args.as_ref())
+            }));
+
+            match result {
+                Ok(response) => response,
+                Err(_) => {
+                    eprintln!("Handler panicked while handling a message.");
+
+                    DaemonResponse::error("Internal error.")
+                }
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to deserialize message: {}", e);
+
+            DaemonResponse::error("Invalid message.")
+        },
+    };
+
+    // Reply to the client (which may have already disconnected, so failures are only logged).
+    if let Ok(response) = to_vec(&response) {
+        if let Err(e) = stream.write_all(&response).await {
+            eprintln!("Failed to reply to TCP connection: {}", e);
+        }
+    }
+}
+
+/// Accept connections on the listener forever, dispatching each client's message.
+///
+/// # Arguments
+/// * `listener` - The listener to accept connections on (bound by the caller, so tests can use an
+///   ephemeral port)
+/// * `handle_args` - Called with the arguments of each `args` message
+pub async fn serve<F>(listener: TcpListener, handle_args: Arc<F>)
+where
+    F: Fn(Vec<String>) + Send + Sync + 'static
+{
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let handle_args: Arc<F> = handle_args.clone();
+
+                // Spawn a new task to handle each client.
+                tokio::spawn(async move {
+                    handle_client(stream, handle_args).await;
+                });
+            },
+            Err(e) => eprintln!("Failed to accept TCP connection: {}", e),
+        }
+    }
+}
\ No newline at end of file
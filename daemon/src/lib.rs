@@ -0,0 +1 @@
+pub mod ipc;
\ No newline at end of file
@@ -1,95 +1,43 @@
 // #![windows_subsystem = "windows"]
 
 use std::{env, process};
-use std::borrow::Cow;
 use std::sync::Arc;
-use serde::{Deserialize, Serialize};
-use serde_json::{from_value, json, Value};
+use serde_json::json;
 use single_instance::SingleInstance;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::net::{TcpListener, TcpStream};
+use tokio::net::TcpListener;
 use tokio::sync::Notify;
 use tray_item::{IconSource, TrayItem};
+use frogworks_core::daemon_client::{DaemonClient, DaemonMessage};
+use frogworks_core::deep_link::DeepLink;
+use frogworks_core::launch_context::{parse_launch_context, LaunchContext};
+use daemon::ipc::{get_daemon_address, serve, DAEMON_IP, DAEMON_PORT, HEARTBEAT_PORT};
 
-const DAEMON_IP: &str = "127.0.0.1";
-const DAEMON_PORT: u16 = 57222;
-const HEARTBEAT_PORT: u16 = 57223;
-
-#[derive(Serialize, Deserialize, Debug)]
-struct Message {
-    r#type: String,
-    data: Value
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-struct ArgsMessage {
-    args: Vec<String>
-}
-
-fn get_tcp_address() -> String {
-    format!("{}:{}", DAEMON_IP, DAEMON_PORT)
-}
-
-async fn handle_client(mut stream: TcpStream) {
-    let mut buffer: Vec<u8> = vec![0; 1024];
-    let n: usize = stream.read(&mut buffer).await.unwrap();
-    let buffer: Cow<str> = String::from_utf8_lossy(&buffer[..n]);
-
-    // Attempt to deserialize the JSON.
-    let message: Message = match serde_json::from_str(&buffer) {
-        Ok(m) => m,
-        Err(e) => {
-            eprintln!("Failed to deserialize arguments: {}", e);
-            return;
-        },
-    };
-
-    handle_message(message)
-}
+fn handle_args(args: Vec<String>) {
+    println!("Args: {:?}", args);
 
-fn handle_message(message: Message) {
-    match message.r#type.as_str() {
-        "args" => {
-            // Parse the arguments.
-            let args_message: ArgsMessage = ArgsMessage { args: from_value(message.data).unwrap() };
-
-            // Pass the arguments along so they can be handled.
-            handle_args(args_message.args)
-        },
-        _ => {
-            println!("Unknown message type: {}", message.r#type);
+    // The OS passes frogworks:// links (registered by the installer) as arguments.
+    for arg in args.iter() {
+        if let Some(deep_link) = DeepLink::parse(arg) {
+            handle_deep_link(deep_link);
         }
     }
 }
 
-fn handle_args(args: Vec<String>) {
-    println!("Args: {:?}", args);
-}
-
-fn generate_message(r#type: &str, data: Value) -> Value {
-    json!({
-        "type": r#type,
-        "data": data
-    })
+fn handle_deep_link(deep_link: DeepLink) {
+    match deep_link {
+        DeepLink::Install(application_id) => println!("Install requested: {}", application_id),
+        DeepLink::Launch(application_id) => println!("Launch requested: {}", application_id),
+        DeepLink::Store(application_id) => println!("Store page requested: {}", application_id)
+    }
 }
 
 async fn start_server() {
     // Start the TCP server.
-    let listener: TcpListener = TcpListener::bind(get_tcp_address()).await.unwrap();
+    let listener: TcpListener = TcpListener::bind(get_daemon_address()).await.unwrap();
 
     println!("TCP server started, listening on {}:{}", DAEMON_IP, DAEMON_PORT);
 
-    loop {
-        match listener.accept().await {
-            Ok((stream, _)) => {
-                // Spawn a new task to handle each client.
-                tokio::spawn(async move {
-                    handle_client(stream).await;
-                });
-            },
-            Err(e) => eprintln!("Failed to accept TCP connection: {}", e),
-        }
-    }
+    serve(listener, Arc::new(handle_args)).await
 }
 
 async fn setup_tray(notify: Arc<Notify>) {
@@ -111,47 +59,11 @@ async fn setup_tray(notify: Arc<Notify>) {
     notify.notified().await
 }
 
-async fn send_to_running_instance(message: Value) -> tokio::io::Result<()> {
-    // Attempt to connect to the running instance's TCP server.
-    let mut stream: TcpStream = TcpStream::connect(get_tcp_address()).await?;
-
-    // Send the message to the daemon.
-    stream.write_all(message.to_string().as_bytes()).await?;
-
-    Ok(())
-}
-
 async fn start_heartbeat_server() {
     let listener: TcpListener =
         TcpListener::bind(format!("{}:{}", DAEMON_IP, HEARTBEAT_PORT)).await.unwrap();
 
-    loop {
-        match listener.accept().await {
-            Ok((stream, _)) => {
-                tokio::spawn(async move {
-                    handle_heartbeat_client(stream).await;
-                });
-            },
-            Err(e) => eprintln!("Failed to accept heartbeat TCP connection: {}", e)
-        }
-    }
-}
-
-async fn handle_heartbeat_client(mut stream: TcpStream) {
-    let mut buffer: Vec<u8> = vec![0; 1024];
-    let n: usize = stream.read(&mut buffer).await.unwrap();
-    let buffer: Cow<str> = String::from_utf8_lossy(&buffer[..n]);
-
-    // Attempt to deserialize the JSON.
-    let message: Message = match serde_json::from_str(&buffer) {
-        Ok(m) => m,
-        Err(e) => {
-            eprintln!("Failed to deserialize arguments: {}", e);
-            return;
-        },
-    };
-
-    handle_message(message)
+    serve(listener, Arc::new(handle_args)).await
 }
 
 #[tokio::main]
@@ -166,14 +78,11 @@ async fn main() {
         // Collect the command line arguments.
         let args: Vec<String> = env::args().skip(1).collect();
 
-        // Serialize the arguments into JSON.
-        let json_args: Value = json!(args);
-
         // Generate the message to be sent to the active daemon.
-        let message: Value = generate_message("args", json_args);
+        let message: DaemonMessage = DaemonMessage::new("args", json!(args));
 
         // Send the message.
-        if let Err(e) = send_to_running_instance(message).await {
+        if let Err(e) = DaemonClient::new().send(&message) {
             eprintln!("Failed to send message to running instance: {}", e);
         }
 
@@ -182,6 +91,15 @@ async fn main() {
 
     println!("Starting daemon instance...");
 
+    // The daemon was started by opening a frogworks:// link (rather than at login), so handle it.
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match parse_launch_context(&args) {
+        LaunchContext::DeepLink(deep_link) => handle_deep_link(deep_link),
+        LaunchContext::InvalidDeepLink(link) => eprintln!("Ignoring invalid link: {}", link),
+        LaunchContext::Interactive => {}
+    }
+
     let notify: Arc<Notify> = Arc::new(Notify::new());
     let notify_clone: Arc<Notify> = notify.clone();
 
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::spawn_blocking;
+use tokio::time::timeout;
+use frogworks_core::daemon_client::{DaemonClient, DaemonMessage, DaemonResponse};
+use daemon::ipc::{handle_message, serve};
+
+/// Start a fake daemon on an ephemeral port, returning its address and a channel receiving the
+/// arguments of each dispatched `args` message.
+async fn start_fake_daemon() -> (String, mpsc::UnboundedReceiver<Vec<String>>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: String = listener.local_addr().unwrap().to_string();
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let sender: UnboundedSender<Vec<String>> = sender;
+
+    tokio::spawn(serve(listener, Arc::new(move |args: Vec<String>| {
+        sender.send(args).unwrap();
+    })));
+
+    (address, receiver)
+}
+
+/// Send a message with the (blocking) daemon client.
+async fn send(address: &str, message: DaemonMessage) -> DaemonResponse {
+    let client: DaemonClient = DaemonClient::new().with_address(address);
+
+    spawn_blocking(move || client.send(&message)).await.unwrap().unwrap()
+}
+
+#[tokio::test]
+async fn forwards_args_to_the_running_daemon() {
+    let (address, mut receiver) = start_fake_daemon().await;
+
+    let args = json!(["frogworks://install/42", "--minimized"]);
+    let response: DaemonResponse = send(&address, DaemonMessage::new("args", args)).await;
+
+    assert!(response.success);
+
+    let received: Vec<String> = timeout(Duration::from_secs(5), receiver.recv())
+        .await
+        .expect("the daemon didn't dispatch the message")
+        .unwrap();
+
+    assert_eq!(received, vec!["frogworks://install/42", "--minimized"]);
+}
+
+#[tokio::test]
+async fn rejects_malformed_messages() {
+    let (address, mut receiver) = start_fake_daemon().await;
+
+    // Not a message at all, so it's written directly rather than through the client.
+    let mut stream: TcpStream = TcpStream::connect(&address).await.unwrap();
+    stream.write_all(json!({"not": "a message"}).to_string().as_bytes()).await.unwrap();
+    stream.shutdown().await.unwrap();
+
+    let mut reply: String = String::new();
+    stream.read_to_string(&mut reply).await.unwrap();
+
+    assert!(!serde_json::from_str::<DaemonResponse>(&reply).unwrap().success);
+
+    let response: DaemonResponse =
+        send(&address, DaemonMessage::new("args", json!("not a list"))).await;
+
+    assert!(!response.success);
+    assert!(timeout(Duration::from_millis(250), receiver.recv()).await.is_err());
+}
+
+#[tokio::test]
+async fn survives_panicking_handlers() {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address: String = listener.local_addr().unwrap().to_string();
+
+    tokio::spawn(serve(listener, Arc::new(|_args: Vec<String>| panic!("the handler failed"))));
+
+    let response: DaemonResponse = send(&address, DaemonMessage::new("args", json!(["a"]))).await;
+
+    assert!(!response.success);
+
+    // The daemon keeps serving after the handler panicked.
+    assert!(send(&address, DaemonMessage::new("ping", json!(null))).await.success);
+}
+
+#[tokio::test]
+async fn closes_abruptly_disconnected_clients() {
+    let (address, _receiver) = start_fake_daemon().await;
+
+    // Disconnect without sending (or waiting for) anything.
+    drop(TcpStream::connect(&address).await.unwrap());
+
+    assert!(send(&address, DaemonMessage::new("ping", json!(null))).await.success);
+}
+
+#[tokio::test]
+async fn answers_pings() {
+    let (address, _receiver) = start_fake_daemon().await;
+
+    assert!(send(&address, DaemonMessage::new("ping", json!(null))).await.success);
+}
+
+#[test]
+fn rejects_unknown_message_types() {
+    let message: DaemonMessage = DaemonMessage::new("unknown", json!(["a"]));
+
+    let response: DaemonResponse =
+        handle_message(message, &|_args: Vec<String>| panic!("unknown messages aren't dispatched"));
+
+    assert!(!response.success);
+}
\ No newline at end of file